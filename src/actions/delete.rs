@@ -1,15 +1,37 @@
-use serenity::all::{ChannelId, Context, DiscordJsonError, ErrorResponse, HttpError};
+use serenity::all::{
+    ChannelId, Context, DiscordJsonError, ErrorResponse, GuildId, HttpError, UserId,
+};
 use sqlx::{Database, Pool};
 
+use crate::locale::LocaleManager;
+use crate::models::audit::snapshot;
+use crate::models::{
+    AuditAction, AuditEntry, AuditManager, ModLog, ModLogManager, ScheduleManager,
+};
+use crate::utils::sync_schedule;
 use crate::{PostManager, Result, templates::TemplateInfo};
 
-pub async fn delete<Db: Database, Manager: PostManager<Db>>(
+/// Deletes the post's thread, message and row. `actor` is `None` when the thread was deleted in
+/// Discord.
+pub async fn delete<
+    Db: Database,
+    Manager: PostManager<Db>
+        + AuditManager<Db>
+        + ModLogManager<Db>
+        + ScheduleManager<Db>
+        + LocaleManager<Db>,
+>(
     ctx: &Context,
     channel: ChannelId,
     pool: &Pool<Db>,
+    guild: Option<GuildId>,
+    actor: Option<UserId>,
 ) -> Result<()> {
-    let Ok(post) = Manager::row(pool, channel).await else {
-        return Ok(());
+    let post = match Manager::row(pool, channel).await {
+        Ok(post) => post,
+        // Already deleted
+        Err(sqlx::Error::RowNotFound) => return Ok(()),
+        Err(e) => return Err(e.into()),
     };
 
     match post.channel().delete(ctx).await {
@@ -19,7 +41,7 @@ pub async fn delete<Db: Database, Manager: PostManager<Db>>(
             error: DiscordJsonError { code: 10003, .. },
             ..
         }))) => {}
-        Err(e) => panic!("{e:?}"),
+        Err(e) => return Err(e.into()),
     }
 
     if let (Some(channel), Some(message)) = (post.alt_channel(), post.alt_message()) {
@@ -30,11 +52,22 @@ pub async fn delete<Db: Database, Manager: PostManager<Db>>(
                 error: DiscordJsonError { code: 10008, .. },
                 ..
             }))) => {}
-            Err(e) => panic!("{e:?}"),
+            Err(e) => return Err(e.into()),
         }
     }
 
-    Manager::delete(pool, channel).await.unwrap();
+    Manager::delete(pool, channel).await?;
+    sync_schedule::<Db, Manager>(ctx, guild).await;
+
+    let entry = AuditEntry::new(channel, AuditAction::Delete)
+        .guild(guild)
+        .actor(actor)
+        .before(snapshot(&post));
+    Manager::record(pool, entry).await?;
+
+    ModLog::PostDeleted { post: &post, actor }
+        .send::<Db, Manager>(ctx, pool, guild)
+        .await;
 
     Ok(())
 }
@@ -1,25 +1,40 @@
 use serenity::all::{
-    ChannelId, CommandInteraction, ComponentInteraction, Context, Mentionable, ResolvedValue,
-    UserId,
+    ChannelId, CommandInteraction, ComponentInteraction, Context, GuildId, Mentionable,
+    ResolvedValue, UserId,
 };
 use sqlx::{Database, Pool};
 use zayden_core::parse_options;
 
-use crate::models::Savable;
-use crate::templates::DefaultTemplate;
-use crate::utils::{Announcement, update_embeds};
-use crate::{Join, PostManager, PostRow, Result};
+use crate::locale::{LocaleManager, guild_locale, message_with};
+use crate::models::{
+    AnnouncementManager, AuditAction, AuditEntry, AuditManager, Savable, ScheduleManager,
+};
+use crate::utils::{
+    Announcement, component_thread, parse_thread, same_guild, sync_schedule, sync_status_tags,
+    sync_thread_title, update_embeds,
+};
+use crate::{
+    Join, PostManager, PostRow, Result, TagMappingManager, TemplateManager, ThreadTitleManager,
+};
 
 pub struct JoinInteraction {
     thread: ChannelId,
+    author: UserId,
     user: UserId,
+    guild: Option<GuildId>,
+    locale: String,
+    guild_locale: Option<String>,
 }
 
 impl From<&ComponentInteraction> for JoinInteraction {
     fn from(value: &ComponentInteraction) -> Self {
         Self {
-            thread: value.channel_id,
+            thread: component_thread(value),
+            author: value.user.id,
             user: value.user.id,
+            guild: value.guild_id,
+            locale: value.locale.clone(),
+            guild_locale: value.guild_locale.clone(),
         }
     }
 }
@@ -32,8 +47,9 @@ impl From<&CommandInteraction> for JoinInteraction {
         };
 
         let mut options = parse_options(subcommand);
+        // The command checks the option is a thread before running the action
         let thread = match options.remove("thread") {
-            Some(ResolvedValue::Channel(channel)) => channel.id,
+            Some(ResolvedValue::String(thread)) => parse_thread(thread).unwrap_or(value.channel_id),
             _ => value.channel_id,
         };
         let user = match options.remove("guardian") {
@@ -41,11 +57,29 @@ impl From<&CommandInteraction> for JoinInteraction {
             _ => value.user.id,
         };
 
-        Self { thread, user }
+        Self {
+            thread,
+            author: value.user.id,
+            user,
+            guild: value.guild_id,
+            locale: value.locale.clone(),
+            guild_locale: value.guild_locale.clone(),
+        }
     }
 }
 
-pub async fn join<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>>(
+pub async fn join<
+    Db: Database,
+    Manager: PostManager<Db>
+        + TagMappingManager<Db>
+        + TemplateManager<Db>
+        + ThreadTitleManager<Db>
+        + LocaleManager<Db>
+        + AuditManager<Db>
+        + AnnouncementManager<Db>
+        + ScheduleManager<Db>
+        + Savable<Db, PostRow>,
+>(
     ctx: &Context,
     interaction: impl Into<JoinInteraction>,
     pool: &Pool<Db>,
@@ -53,20 +87,70 @@ pub async fn join<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>>
 ) -> Result<String> {
     let interaction = interaction.into();
 
-    let mut row = Manager::row(pool, interaction.thread).await.unwrap();
+    let mut row = Manager::row(pool, interaction.thread).await?;
+    same_guild(ctx, &row, interaction.thread, interaction.guild).await?;
+    let promoted = !alternative && row.alternatives().any(|user| user == interaction.user);
     row.join(interaction.user, alternative)?;
 
-    let owner = row.owner().to_user(ctx).await.unwrap();
+    let locale =
+        guild_locale::<Db, Manager>(pool, interaction.guild, interaction.guild_locale.as_deref())
+            .await?;
+
+    Manager::save(pool, row.clone()).await?;
+
+    let mut entry = AuditEntry::new(interaction.thread, AuditAction::Join)
+        .guild(interaction.guild)
+        .actor(Some(interaction.author))
+        .after(if alternative {
+            "Alternative"
+        } else {
+            "Fireteam"
+        });
+    if interaction.user != interaction.author {
+        entry = entry.target(interaction.user);
+    }
+    Manager::record(pool, entry).await?;
+
+    // The user has joined, so a failure past here is logged rather than returned
+    let thread = interaction.thread;
 
-    update_embeds::<DefaultTemplate>(ctx, &row, owner.display_name(), interaction.thread).await;
-    Announcement::Joined {
-        user: interaction.user,
-        alternative,
+    match row.owner().to_user(ctx).await {
+        Ok(owner) => {
+            if let Err(e) =
+                update_embeds::<Db, Manager>(ctx, pool, &row, owner.display_name(), thread, &locale)
+                    .await
+            {
+                eprintln!("Failed to update the embeds of '{thread}': {e:?}");
+            }
+        }
+        Err(e) => eprintln!("Failed to load the owner of '{thread}': {e:?}"),
+    }
+    if let Err(e) = sync_status_tags::<Db, Manager>(ctx, pool, &row, thread).await {
+        eprintln!("Failed to sync the status tags of '{thread}': {e:?}");
     }
-    .send(ctx, interaction.thread)
-    .await;
+    if let Err(e) = sync_thread_title::<Db, Manager>(ctx, pool, &row, thread, None).await {
+        eprintln!("Failed to rename '{thread}': {e:?}");
+    }
+    sync_schedule::<Db, Manager>(ctx, interaction.guild).await;
 
-    Manager::save(pool, row).await.unwrap();
+    let announcement = if promoted {
+        Announcement::Promoted(interaction.user)
+    } else {
+        Announcement::Joined {
+            user: interaction.user,
+            alternative,
+        }
+    };
+    if let Err(e) = announcement
+        .send::<Db, Manager>(ctx, pool, interaction.guild, thread, &locale)
+        .await
+    {
+        eprintln!("Failed to announce the join in '{thread}': {e:?}");
+    }
 
-    Ok(format!("You have joined {}", interaction.thread.mention()))
+    Ok(message_with(
+        &interaction.locale,
+        "response.joined",
+        &[("thread", &thread.mention())],
+    ))
 }
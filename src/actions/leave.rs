@@ -1,21 +1,31 @@
 use serenity::all::{
     ChannelId, CommandInteraction, ComponentInteraction, ComponentInteractionDataKind, Context,
-    Mentionable, ResolvedValue, UserId,
+    GuildId, Mentionable, ResolvedValue, UserId,
 };
 use sqlx::{Database, Pool};
 use zayden_core::parse_options;
 
 use crate::{
-    Leave, PostManager, PostRow, Result,
-    models::Savable,
-    templates::DefaultTemplate,
-    utils::{Announcement, update_embeds},
+    Error, Join, Leave, PostManager, PostRow, Result, TagMappingManager, TemplateManager,
+    ThreadTitleManager,
+    locale::{LocaleManager, guild_locale, message_with},
+    models::{
+        AnnouncementManager, AuditAction, AuditEntry, AuditManager, ModLog, ModLogManager, Savable,
+        ScheduleManager,
+    },
+    utils::{
+        Announcement, component_thread, parse_thread, same_guild, sync_schedule, sync_status_tags,
+        sync_thread_title, update_embeds,
+    },
 };
 
 pub struct LeaveInteraction {
     thread: ChannelId,
     author: UserId,
     user: UserId,
+    guild: Option<GuildId>,
+    locale: String,
+    guild_locale: Option<String>,
 }
 
 impl From<&CommandInteraction> for LeaveInteraction {
@@ -26,8 +36,9 @@ impl From<&CommandInteraction> for LeaveInteraction {
         };
 
         let mut options = parse_options(subcommand);
+        // The command checks the option is a thread before running the action
         let thread = match options.remove("thread") {
-            Some(ResolvedValue::Channel(channel)) => channel.id,
+            Some(ResolvedValue::String(thread)) => parse_thread(thread).unwrap_or(value.channel_id),
             _ => value.channel_id,
         };
         let user = match options.remove("guardian") {
@@ -39,6 +50,9 @@ impl From<&CommandInteraction> for LeaveInteraction {
             thread,
             author: value.user.id,
             user,
+            guild: value.guild_id,
+            locale: value.locale.clone(),
+            guild_locale: value.guild_locale.clone(),
         }
     }
 }
@@ -51,39 +65,127 @@ impl From<&ComponentInteraction> for LeaveInteraction {
         };
 
         Self {
-            thread: value.channel_id,
+            thread: component_thread(value),
             author: value.user.id,
             user,
+            guild: value.guild_id,
+            locale: value.locale.clone(),
+            guild_locale: value.guild_locale.clone(),
         }
     }
 }
 
-pub async fn leave<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>>(
+pub async fn leave<
+    Db: Database,
+    Manager: PostManager<Db>
+        + TagMappingManager<Db>
+        + TemplateManager<Db>
+        + ThreadTitleManager<Db>
+        + LocaleManager<Db>
+        + AuditManager<Db>
+        + AnnouncementManager<Db>
+        + ScheduleManager<Db>
+        + ModLogManager<Db>
+        + Savable<Db, PostRow>,
+>(
     ctx: &Context,
     interaction: impl Into<LeaveInteraction>,
     pool: &Pool<Db>,
 ) -> Result<String> {
     let interaction = interaction.into();
 
-    let mut row = Manager::row(pool, interaction.thread).await.unwrap();
+    let mut row = Manager::row(pool, interaction.thread).await?;
+    same_guild(ctx, &row, interaction.thread, interaction.guild).await?;
+
+    // Leaving a post the user isn't in would record and announce a change that didn't happen
+    if !row
+        .fireteam()
+        .chain(row.alternatives())
+        .any(|user| user == interaction.user)
+    {
+        return Err(Error::NotInFireteam);
+    }
+
     row.leave(interaction.user);
 
-    let owner = row.owner().to_user(ctx).await.unwrap();
+    let owner_id = row.owner();
 
-    update_embeds::<DefaultTemplate>(ctx, &row, owner.display_name(), interaction.thread).await;
-    Announcement::Left(interaction.user)
-        .send(ctx, interaction.thread)
+    let locale =
+        guild_locale::<Db, Manager>(pool, interaction.guild, interaction.guild_locale.as_deref())
+            .await?;
+
+    Manager::save(pool, row.clone()).await?;
+
+    let entry = if interaction.author == interaction.user {
+        AuditEntry::new(interaction.thread, AuditAction::Leave)
+    } else {
+        AuditEntry::new(interaction.thread, AuditAction::Kick).target(interaction.user)
+    };
+    Manager::record(
+        pool,
+        entry
+            .guild(interaction.guild)
+            .actor(Some(interaction.author)),
+    )
+    .await?;
+
+    // The user has left, so a failure past here is logged rather than returned
+    let thread = interaction.thread;
+
+    match owner_id.to_user(ctx).await {
+        Ok(owner) => {
+            if let Err(e) =
+                update_embeds::<Db, Manager>(ctx, pool, &row, owner.display_name(), thread, &locale)
+                    .await
+            {
+                eprintln!("Failed to update the embeds of '{thread}': {e:?}");
+            }
+        }
+        Err(e) => eprintln!("Failed to load the owner of '{thread}': {e:?}"),
+    }
+    if let Err(e) = sync_status_tags::<Db, Manager>(ctx, pool, &row, thread).await {
+        eprintln!("Failed to sync the status tags of '{thread}': {e:?}");
+    }
+    if let Err(e) = sync_thread_title::<Db, Manager>(ctx, pool, &row, thread, None).await {
+        eprintln!("Failed to rename '{thread}': {e:?}");
+    }
+    sync_schedule::<Db, Manager>(ctx, interaction.guild).await;
+
+    let announcement = if interaction.author == interaction.user {
+        Announcement::Left(interaction.user)
+    } else {
+        Announcement::Kicked {
+            user: interaction.user,
+            actor: interaction.author,
+        }
+    };
+    if let Err(e) = announcement
+        .send::<Db, Manager>(ctx, pool, interaction.guild, thread, &locale)
+        .await
+    {
+        eprintln!("Failed to announce the leave in '{thread}': {e:?}");
+    }
+
+    if interaction.author != interaction.user {
+        ModLog::Kicked {
+            thread: interaction.thread,
+            user: interaction.user,
+            owner: owner_id,
+            actor: interaction.author,
+        }
+        .send::<Db, Manager>(ctx, pool, interaction.guild)
         .await;
+    }
 
-    Manager::save(pool, row).await.unwrap();
+    let thread = thread.mention();
 
     let content = if interaction.author == interaction.user {
-        format!("You have left {}", interaction.thread.mention())
+        message_with(&interaction.locale, "response.left", &[("thread", &thread)])
     } else {
-        format!(
-            "{} have left {}",
-            interaction.user.mention(),
-            interaction.thread.mention()
+        message_with(
+            &interaction.locale,
+            "response.removed",
+            &[("user", &interaction.user.mention()), ("thread", &thread)],
         )
     };
 
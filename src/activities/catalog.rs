@@ -0,0 +1,279 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::SystemTime;
+
+use serde::Deserialize;
+use serenity::all::GuildId;
+use sqlx::{Database, Pool};
+use zayden_core::CronJob;
+
+use crate::{CustomActivityManager, CustomActivityRow, Error, Result};
+
+use super::{Activity, Game, destiny, resolve};
+
+static CATALOG: LazyLock<RwLock<CatalogState>> =
+    LazyLock::new(|| RwLock::new(CatalogState::default()));
+
+/// The custom activities of each guild, as last loaded, so posts render with them.
+static CUSTOM: LazyLock<RwLock<HashMap<GuildId, Arc<[CustomActivityRow]>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+struct CatalogState {
+    games: Arc<[Game]>,
+    modified: Option<SystemTime>,
+}
+
+impl Default for CatalogState {
+    fn default() -> Self {
+        Self {
+            games: Arc::from(built_in()),
+            modified: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CatalogFile {
+    #[serde(rename = "game", default)]
+    games: Vec<Game>,
+}
+
+/// The games and activities offered by the bot.
+///
+/// Starts out as the built-in Destiny 2 catalog. Games can be added, or the built-in ones
+/// replaced, at runtime by a TOML file:
+///
+/// ```toml
+/// [[game]]
+/// id = "destiny2"
+/// name = "Destiny 2"
+/// categories = ["Raid", "Dungeon"]
+///
+/// [[game.activity]]
+/// name = "Salvation's Edge"
+/// category = "Raid"
+/// fireteam_size = 6
+/// max_fireteam_size = 6
+/// aliases = ["SE"]
+/// difficulties = ["Normal", "Master", "Contest"]
+/// duration = 120
+/// thumbnail = "https://example.com/salvations-edge.png"
+/// colour = 0x2E86C1
+/// ```
+pub struct ActivityCatalog;
+
+impl ActivityCatalog {
+    pub fn games() -> Arc<[Game]> {
+        CATALOG.read().unwrap().games.clone()
+    }
+
+    pub fn game(id: &str) -> Option<Game> {
+        Self::games()
+            .iter()
+            .find(|game| game.id.eq_ignore_ascii_case(id))
+            .cloned()
+    }
+
+    pub fn activities(game: &str) -> Vec<Activity> {
+        Self::game(game)
+            .map(|game| game.activities)
+            .unwrap_or_default()
+    }
+
+    pub fn find(game: &str, name: &str) -> Option<Activity> {
+        resolve(&Self::activities(game), name).cloned()
+    }
+
+    /// Like [`Self::find`], also looking through the guild's custom activities as last loaded
+    /// by [`Self::load_custom`].
+    pub fn find_for(guild: Option<GuildId>, game: &str, name: &str) -> Option<Activity> {
+        let custom = guild.and_then(|guild| CUSTOM.read().unwrap().get(&guild).cloned());
+
+        match custom {
+            Some(custom) => resolve(&Self::with_custom(game, &custom), name).cloned(),
+            None => Self::find(game, name),
+        }
+    }
+
+    /// Loads the guild's custom activities, keeping them for [`Self::find_for`].
+    pub async fn load_custom<Db: Database, Manager: CustomActivityManager<Db>>(
+        pool: &Pool<Db>,
+        guild: GuildId,
+    ) -> sqlx::Result<Arc<[CustomActivityRow]>> {
+        let custom = Arc::from(Manager::activities(pool, guild).await?);
+
+        CUSTOM.write().unwrap().insert(guild, Arc::clone(&custom));
+
+        Ok(custom)
+    }
+
+    /// The game's catalog merged with a guild's custom activities, which take precedence.
+    pub fn with_custom(game: &str, custom: &[CustomActivityRow]) -> Vec<Activity> {
+        let custom = custom
+            .iter()
+            .filter(|row| row.game().eq_ignore_ascii_case(game))
+            .collect::<Vec<_>>();
+
+        let global = Self::activities(game)
+            .into_iter()
+            .filter(|activity| {
+                !custom
+                    .iter()
+                    .any(|row| row.name.eq_ignore_ascii_case(&activity.name))
+            })
+            .collect::<Vec<_>>();
+
+        custom
+            .into_iter()
+            .map(Activity::from)
+            .chain(global)
+            .collect()
+    }
+
+    /// Loads the games in `path`, replacing any built-in game with the same id.
+    ///
+    /// On error the current catalog is left untouched.
+    pub fn load(path: impl AsRef<Path>) -> Result<usize> {
+        let path = path.as_ref();
+
+        let modified = path.metadata().and_then(|meta| meta.modified()).ok();
+        let games = parse(path)?;
+
+        let mut all = built_in();
+        all.retain(|built_in| !games.iter().any(|game| game.id == built_in.id));
+        all.extend(games);
+        let games = all;
+
+        let len = games.iter().map(|game| game.activities.len()).sum();
+
+        let mut state = CATALOG.write().unwrap();
+        state.games = Arc::from(games);
+        state.modified = modified;
+
+        Ok(len)
+    }
+
+    /// Loads `path` if it exists, otherwise falls back to the built-in games.
+    pub fn load_or_default(path: impl AsRef<Path>) -> Result<usize> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            let state = CatalogState::default();
+            let len = state.games.iter().map(|game| game.activities.len()).sum();
+            *CATALOG.write().unwrap() = state;
+            return Ok(len);
+        }
+
+        Self::load(path)
+    }
+
+    /// Reloads `path` if it has been modified since it was last loaded.
+    pub fn reload(path: impl AsRef<Path>) -> Result<Option<usize>> {
+        let path = path.as_ref();
+
+        let Ok(modified) = path.metadata().and_then(|meta| meta.modified()) else {
+            return Ok(None);
+        };
+
+        if CATALOG.read().unwrap().modified == Some(modified) {
+            return Ok(None);
+        }
+
+        Self::load(path).map(Some)
+    }
+
+    /// A job that checks `path` for changes every minute and hot reloads the catalog.
+    pub fn reload_job<Db: Database>(path: impl Into<PathBuf>) -> CronJob<Db> {
+        let path = path.into();
+
+        CronJob::<Db>::new("0 * * * * * *").set_action(move |_ctx, _pool| {
+            let path = path.clone();
+
+            async move {
+                if let Err(e) = ActivityCatalog::reload(&path) {
+                    eprintln!("{e}");
+                }
+            }
+        })
+    }
+}
+
+fn built_in() -> Vec<Game> {
+    vec![destiny::game()]
+}
+
+fn parse(path: &Path) -> Result<Vec<Game>> {
+    let invalid = |reason: String| Error::InvalidCatalog {
+        path: path.to_path_buf(),
+        reason,
+    };
+
+    let content = std::fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+
+    let mut file = toml::from_str::<CatalogFile>(&content).map_err(|e| invalid(e.to_string()))?;
+
+    if file.games.is_empty() {
+        return Err(invalid(String::from("no games defined")));
+    }
+
+    let mut ids = HashSet::new();
+
+    for (i, game) in file.games.iter_mut().enumerate() {
+        let id = game.id.trim();
+
+        if id.is_empty() || id.contains(|c: char| !c.is_ascii_alphanumeric() && c != '-') {
+            return Err(invalid(format!(
+                "game #{} must have an id made of letters, numbers and dashes",
+                i + 1
+            )));
+        }
+
+        if !ids.insert(id.to_lowercase()) {
+            return Err(invalid(format!("game '{id}' is defined more than once")));
+        }
+
+        if game.activities.is_empty() {
+            return Err(invalid(format!("game '{id}' has no activities defined")));
+        }
+
+        let mut names = HashSet::new();
+
+        for (i, activity) in game.activities.iter().enumerate() {
+            let name = activity.name.trim();
+
+            if name.is_empty() {
+                return Err(invalid(format!(
+                    "activity #{} of game '{id}' has an empty name",
+                    i + 1
+                )));
+            }
+
+            if activity.fireteam_size < 1 {
+                return Err(invalid(format!(
+                    "activity '{name}' must have a fireteam size of at least 1"
+                )));
+            }
+
+            if !activity.supports_fireteam_size(activity.fireteam_size) {
+                return Err(invalid(format!(
+                    "activity '{name}' has a fireteam size outside of its min and max fireteam size"
+                )));
+            }
+
+            if !names.insert(name.to_lowercase()) {
+                return Err(invalid(format!(
+                    "activity '{name}' is defined more than once in game '{id}'"
+                )));
+            }
+        }
+
+        for activity in &game.activities {
+            if !game.categories.contains(&activity.category) {
+                game.categories.push(activity.category.clone());
+            }
+        }
+    }
+
+    Ok(file.games)
+}
@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+use std::sync::LazyLock;
+
+use super::{Activity, ActivityCategory, DEFAULT_GAME, Difficulty, Game};
+
+pub const RAID: ActivityCategory = ActivityCategory::new("Raid");
+pub const DUNGEON: ActivityCategory = ActivityCategory::new("Dungeon");
+pub const EXOTIC_MISSION: ActivityCategory = ActivityCategory::new("Exotic Mission");
+pub const VANGUARD: ActivityCategory = ActivityCategory::new("Vanguard");
+pub const PVP: ActivityCategory = ActivityCategory::new("PvP");
+
+pub static ACTIVITIES: LazyLock<[Activity; 40]> = LazyLock::new(|| {
+    [
+        //region: Raids
+        Activity::new("Salvation's Edge", RAID, 6)
+            .aliases(["SE"])
+            .difficulties([Difficulty::Normal, Difficulty::Master, Difficulty::Contest]),
+        Activity::new("Crota's End", RAID, 6)
+            .aliases(["Crota", "CE"])
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Root of Nightmares", RAID, 6)
+            .aliases(["RoN"])
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("King's Fall", RAID, 6)
+            .aliases(["KF"])
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Vow of the Disciple", RAID, 6)
+            .aliases(["VotD", "VoD"])
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Vault of Glass", RAID, 6)
+            .aliases(["VoG"])
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Deep Stone Crypt", RAID, 6).aliases(["DSC"]),
+        Activity::new("Garden of Salvation", RAID, 6).aliases(["GoS"]),
+        Activity::new("Last Wish", RAID, 6).aliases(["LW"]),
+        Activity::new("Wrath of the Machine", RAID, 6).aliases(["WotM"]),
+        Activity::new("Any Raid", RAID, 6).difficulties([
+            Difficulty::Normal,
+            Difficulty::Master,
+            Difficulty::Contest,
+        ]),
+        //endregion
+        //region: Dungeons
+        Activity::new("Sundered Doctrine", DUNGEON, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Vesper's Host", DUNGEON, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Warlord's Ruin", DUNGEON, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Ghosts of the Deep", DUNGEON, 3)
+            .aliases(["GotD"])
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Spire of the Watcher", DUNGEON, 3)
+            .aliases(["SotW"])
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Duality", DUNGEON, 3).difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Grasp of Avarice", DUNGEON, 3)
+            .aliases(["GoA"])
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Prophecy", DUNGEON, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Pit of Heresy", DUNGEON, 3).aliases(["PoH"]),
+        Activity::new("Shattered Throne", DUNGEON, 3).aliases(["ST"]),
+        Activity::new("Any Dungeon", DUNGEON, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        //endregion
+        Activity::new("Kell's Fall", EXOTIC_MISSION, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Legend]),
+        Activity::new("Duel Destiny", EXOTIC_MISSION, 2)
+            .difficulties([Difficulty::Normal, Difficulty::Legend]),
+        Activity::new("The Whisper", EXOTIC_MISSION, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Legend]),
+        Activity::new("Zero Hour", EXOTIC_MISSION, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Legend]),
+        Activity::new("Harbinger", EXOTIC_MISSION, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Legend]),
+        Activity::new("Presage", EXOTIC_MISSION, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Vox Obscura", EXOTIC_MISSION, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Master]),
+        Activity::new("Operation: Seraph's Shield", EXOTIC_MISSION, 3).aliases(["Seraph's Shield"]),
+        Activity::new("Node.Ovrd.Avalon", EXOTIC_MISSION, 3)
+            .aliases(["Avalon"])
+            .difficulties([Difficulty::Normal, Difficulty::Legend]),
+        Activity::new("Starcrossed", EXOTIC_MISSION, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Legend]),
+        Activity::new("Vanguard Ops", VANGUARD, 3),
+        Activity::new("Nightfall", VANGUARD, 3)
+            .aliases(["NF"])
+            .difficulties([Difficulty::Hero, Difficulty::Legend, Difficulty::Master]),
+        Activity::new("Grandmaster", VANGUARD, 3).aliases(["GM"]),
+        Activity::new("Onslaught", VANGUARD, 3)
+            .difficulties([Difficulty::Normal, Difficulty::Legend]),
+        Activity::new("Crucible", PVP, 6).aliases(["Quickplay", "Control"]),
+        Activity::new("Competitive", PVP, 6).aliases(["Comp", "Survival"]),
+        Activity::new("Iron Banner", PVP, 6).aliases(["IB"]),
+        Activity::new("Trials of Osiris", PVP, 3)
+            .aliases(["Trials", "ToO"])
+            .difficulties([Difficulty::Normal, Difficulty::Flawless]),
+    ]
+    .map(|activity| {
+        let duration = default_duration(&activity.category);
+        activity.duration(duration)
+    })
+});
+
+pub fn game() -> Game {
+    Game {
+        id: Cow::Borrowed(DEFAULT_GAME),
+        name: Cow::Borrowed("Destiny 2"),
+        categories: vec![RAID, DUNGEON, EXOTIC_MISSION, VANGUARD, PVP],
+        activities: ACTIVITIES.to_vec(),
+    }
+}
+
+fn default_duration(category: &ActivityCategory) -> Option<u16> {
+    match category.name() {
+        "Raid" => Some(120),
+        "Dungeon" => Some(90),
+        "Exotic Mission" => Some(60),
+        "Vanguard" => Some(45),
+        "PvP" => Some(60),
+        _ => None,
+    }
+}
@@ -0,0 +1,197 @@
+mod catalog;
+pub mod destiny;
+mod search;
+
+use core::fmt;
+use std::borrow::Cow;
+use std::str::FromStr;
+
+pub use catalog::ActivityCatalog;
+pub use destiny::ACTIVITIES;
+pub use search::{rank, resolve};
+use serde::Deserialize;
+use serenity::all::Colour;
+
+pub const DEFAULT_GAME: &str = "destiny2";
+
+/// A game and the catalog of activities that can be organised for it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Game {
+    pub id: Cow<'static, str>,
+    pub name: Cow<'static, str>,
+    #[serde(default)]
+    pub categories: Vec<ActivityCategory>,
+    #[serde(rename = "activity", default)]
+    pub activities: Vec<Activity>,
+}
+
+impl Game {
+    pub fn category(&self, name: &str) -> Option<&ActivityCategory> {
+        self.categories
+            .iter()
+            .find(|category| category.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Activity {
+    pub name: Cow<'static, str>,
+    pub category: ActivityCategory,
+    pub fireteam_size: i16,
+    #[serde(default = "default_min_fireteam_size")]
+    pub min_fireteam_size: i16,
+    #[serde(default = "default_max_fireteam_size")]
+    pub max_fireteam_size: i16,
+    #[serde(default)]
+    pub aliases: Vec<Cow<'static, str>>,
+    #[serde(default)]
+    pub difficulties: Vec<Difficulty>,
+    /// Expected duration in minutes
+    #[serde(default)]
+    pub duration: Option<u16>,
+    #[serde(default)]
+    pub thumbnail: Option<Cow<'static, str>>,
+    #[serde(default)]
+    pub colour: Option<Colour>,
+}
+
+impl Activity {
+    fn new(name: &'static str, category: ActivityCategory, fireteam_size: i16) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            category,
+            fireteam_size,
+            min_fireteam_size: 1,
+            max_fireteam_size: fireteam_size,
+            aliases: Vec::new(),
+            difficulties: Vec::new(),
+            duration: None,
+            thumbnail: None,
+            colour: None,
+        }
+    }
+
+    fn aliases<const N: usize>(mut self, aliases: [&'static str; N]) -> Self {
+        self.aliases = aliases.into_iter().map(Cow::Borrowed).collect();
+        self
+    }
+
+    fn duration(mut self, duration: Option<u16>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    fn difficulties<const N: usize>(mut self, difficulties: [Difficulty; N]) -> Self {
+        self.difficulties = difficulties.to_vec();
+        self
+    }
+
+    pub fn supports_fireteam_size(&self, size: i16) -> bool {
+        (self.min_fireteam_size..=self.max_fireteam_size).contains(&size)
+    }
+}
+
+fn default_min_fireteam_size() -> i16 {
+    1
+}
+
+fn default_max_fireteam_size() -> i16 {
+    i16::MAX
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(transparent)]
+pub struct ActivityCategory(Cow<'static, str>);
+
+impl ActivityCategory {
+    pub const fn new(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// The embed colour of the category. Unknown categories get a stable colour from their name.
+    pub fn colour(&self) -> Colour {
+        const PALETTE: [Colour; 6] = [
+            Colour::BLUE,
+            Colour::TEAL,
+            Colour::ORANGE,
+            Colour::MAGENTA,
+            Colour::DARK_GREEN,
+            Colour::GOLD,
+        ];
+
+        match self.0.to_lowercase().as_str() {
+            "raid" => Colour::DARK_PURPLE,
+            "dungeon" => Colour::DARK_TEAL,
+            "exotic mission" => Colour::GOLD,
+            "vanguard" => Colour::BLUE,
+            "pvp" => Colour::RED,
+            name => {
+                let hash = name.bytes().fold(0usize, |hash, b| {
+                    hash.wrapping_mul(31).wrapping_add(b as usize)
+                });
+                PALETTE[hash % PALETTE.len()]
+            }
+        }
+    }
+}
+
+impl From<String> for ActivityCategory {
+    fn from(value: String) -> Self {
+        Self(Cow::Owned(value))
+    }
+}
+
+impl fmt::Display for ActivityCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Difficulty {
+    Normal,
+    Hero,
+    Legend,
+    Master,
+    Contest,
+    Flawless,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 6] = [
+        Self::Normal,
+        Self::Hero,
+        Self::Legend,
+        Self::Master,
+        Self::Contest,
+        Self::Flawless,
+    ];
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normal => write!(f, "Normal"),
+            Self::Hero => write!(f, "Hero"),
+            Self::Legend => write!(f, "Legend"),
+            Self::Master => write!(f, "Master"),
+            Self::Contest => write!(f, "Contest"),
+            Self::Flawless => write!(f, "Flawless"),
+        }
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or(())
+    }
+}
@@ -0,0 +1,269 @@
+use std::iter;
+
+use super::Activity;
+
+/// Activities matching `query`, best match first.
+///
+/// Matches on the name and aliases by exact name, acronym ("VoG"), prefix, word prefix ("crota"),
+/// substring and finally edit distance to tolerate typos.
+pub fn rank<'a>(activities: &'a [Activity], query: &str) -> Vec<&'a Activity> {
+    let query = normalise(query);
+
+    if query.is_empty() {
+        return activities.iter().collect();
+    }
+
+    let mut matches = activities
+        .iter()
+        .filter_map(|activity| score(activity, &query).map(|score| (score, activity)))
+        .collect::<Vec<_>>();
+
+    // Stable sort so equal scores keep the catalog order
+    matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    matches.into_iter().map(|(_, activity)| activity).collect()
+}
+
+/// The activity `query` most likely refers to.
+pub fn resolve<'a>(activities: &'a [Activity], query: &str) -> Option<&'a Activity> {
+    if normalise(query).is_empty() {
+        return None;
+    }
+
+    rank(activities, query).into_iter().next()
+}
+
+fn score(activity: &Activity, query: &str) -> Option<u32> {
+    iter::once(&activity.name)
+        .chain(&activity.aliases)
+        .filter_map(|name| score_name(&normalise(name), query))
+        .max()
+}
+
+fn score_name(name: &str, query: &str) -> Option<u32> {
+    if name.is_empty() {
+        return None;
+    }
+
+    if name == query {
+        return Some(100);
+    }
+
+    if name.contains(' ') && acronym(name) == query {
+        return Some(90);
+    }
+
+    // The query mentions the whole name, e.g. "vault of glass master"
+    if format!(" {query} ").contains(&format!(" {name} ")) {
+        return Some(85);
+    }
+
+    if name.starts_with(query) {
+        return Some(80);
+    }
+
+    if name.split(' ').any(|word| word.starts_with(query)) {
+        return Some(70);
+    }
+
+    if name.contains(query) {
+        return Some(50);
+    }
+
+    let max_distance = match query.chars().count() {
+        0..=3 => return None,
+        4..=7 => 1,
+        _ => 2,
+    };
+
+    let prefix = name.chars().take(query.chars().count()).collect::<String>();
+
+    let distance = iter::once(name)
+        .chain(iter::once(prefix.as_str()))
+        .chain(name.split(' '))
+        .map(|candidate| levenshtein(candidate, query))
+        .min()?;
+
+    (distance <= max_distance).then(|| 40 - distance as u32)
+}
+
+/// Lowercases, drops apostrophes and turns any other punctuation into a single space.
+fn normalise(s: &str) -> String {
+    s.chars()
+        .filter(|c| *c != '\'' && *c != '’')
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn acronym(name: &str) -> String {
+    name.split(' ')
+        .filter_map(|word| word.chars().next())
+        .collect()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, b) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if a == *b {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(current)
+            };
+            prev = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activities::destiny::{DUNGEON, RAID};
+
+    fn activities() -> Vec<Activity> {
+        vec![
+            Activity::new("Crota's End", RAID, 6).aliases(["Crota", "CE"]),
+            Activity::new("Vault of Glass", RAID, 6).aliases(["VoG"]),
+            Activity::new("Vow of the Disciple", RAID, 6).aliases(["Vow"]),
+            Activity::new("Root of Nightmares", RAID, 6).aliases(["RoN"]),
+            Activity::new("Warlord's Ruin", DUNGEON, 3),
+            Activity::new("Duality", DUNGEON, 3),
+        ]
+    }
+
+    fn resolved(activities: &[Activity], query: &str) -> Option<String> {
+        resolve(activities, query).map(|activity| activity.name.to_string())
+    }
+
+    #[test]
+    fn normalise_drops_apostrophes_and_punctuation() {
+        assert_eq!(normalise("  Crota's   End! "), "crotas end");
+        assert_eq!(normalise("Warlord’s-Ruin"), "warlords ruin");
+    }
+
+    #[test]
+    fn exact_name_and_alias() {
+        let activities = activities();
+
+        assert_eq!(resolved(&activities, "crotas end").unwrap(), "Crota's End");
+        assert_eq!(resolved(&activities, "CE").unwrap(), "Crota's End");
+        assert_eq!(resolved(&activities, "vow").unwrap(), "Vow of the Disciple");
+    }
+
+    #[test]
+    fn acronym_of_the_name() {
+        assert_eq!(score_name("vault of glass", "vog"), Some(90));
+        assert_eq!(score_name("root of nightmares", "ron"), Some(90));
+        // Single words don't have an acronym
+        assert_eq!(score_name("duality", "d"), Some(80));
+    }
+
+    #[test]
+    fn query_containing_the_name() {
+        assert_eq!(
+            resolved(&activities(), "Vault of Glass master").unwrap(),
+            "Vault of Glass"
+        );
+        assert_eq!(
+            score_name("vault of glass", "vault of glass master"),
+            Some(85)
+        );
+    }
+
+    #[test]
+    fn tiers_are_ordered() {
+        let exact = score_name("duality", "duality");
+        let acronym = score_name("vault of glass", "vog");
+        let contained = score_name("crota", "crota flawless");
+        let prefix = score_name("vault of glass", "vau");
+        let word_prefix = score_name("root of nightmares", "night");
+        let substring = score_name("duality", "ual");
+        let typo = score_name("duality", "dualty");
+
+        assert!(exact > acronym);
+        assert!(acronym > contained);
+        assert!(contained > prefix);
+        assert!(prefix > word_prefix);
+        assert!(word_prefix > substring);
+        assert!(substring > typo);
+        assert!(typo.is_some());
+    }
+
+    #[test]
+    fn prefix_beats_a_later_word() {
+        // "vo" starts "Vault of Glass" and "Vow of the Disciple", catalog order breaks the tie
+        let ranked = rank(&activities(), "vo")
+            .into_iter()
+            .map(|activity| activity.name.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ranked[..2], ["Vault of Glass", "Vow of the Disciple"]);
+    }
+
+    #[test]
+    fn typos_within_the_distance() {
+        let activities = activities();
+
+        assert_eq!(resolved(&activities, "dualty").unwrap(), "Duality");
+        assert_eq!(
+            resolved(&activities, "warlods ruin").unwrap(),
+            "Warlord's Ruin"
+        );
+        assert_eq!(resolved(&activities, "vualt of glsas"), None);
+    }
+
+    #[test]
+    fn short_queries_are_not_fuzzy() {
+        assert_eq!(score_name("duality", "dux"), None);
+        assert_eq!(resolved(&activities(), "xyz"), None);
+    }
+
+    #[test]
+    fn empty_query() {
+        assert_eq!(resolved(&activities(), "  "), None);
+        assert_eq!(rank(&activities(), "").len(), activities().len());
+    }
+
+    #[test]
+    fn custom_activity_needs_the_guild_catalog() {
+        // Only the built-in catalog: the alias "Crota" is contained in the name
+        assert_eq!(
+            resolved(&activities(), "Crota Flawless Carry").unwrap(),
+            "Crota's End"
+        );
+
+        // Custom activities come first, and the exact name wins
+        let mut custom = vec![Activity::new("Crota Flawless Carry", RAID, 12)];
+        custom.extend(activities());
+
+        assert_eq!(
+            resolved(&custom, "Crota Flawless Carry").unwrap(),
+            "Crota Flawless Carry"
+        );
+    }
+
+    #[test]
+    fn levenshtein_distance() {
+        assert_eq!(levenshtein("duality", "duality"), 0);
+        assert_eq!(levenshtein("duality", "dualty"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+}
@@ -0,0 +1,293 @@
+//! iCalendar (RFC 5545) exports of posts.
+
+#[cfg(feature = "calendar-server")]
+pub mod server;
+
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Duration, Utc};
+use serenity::all::{ChannelId, GuildId, UserId};
+use sqlx::{Database, Pool};
+
+use crate::cron::reminders::REMINDER_OFFSETS_MINS;
+use crate::templates::TemplateInfo;
+use crate::{JoinedManager, JoinedRow, PostManager, PostRow};
+
+/// Posts have no end time, so their events last this long.
+pub const EVENT_HOURS: i64 = 2;
+/// The longest a content line can be before it's folded, in octets.
+const LINE_LIMIT: usize = 75;
+/// The random bytes in a feed token.
+const TOKEN_BYTES: usize = 32;
+
+/// The public URL of the feed server, set once it's running.
+static FEED_URL: OnceLock<String> = OnceLock::new();
+
+/// The link of the token's feed, if the feed server is running.
+pub fn feed_url(token: &str) -> Option<String> {
+    FEED_URL
+        .get()
+        .map(|url| format!("{url}/calendar/{token}.ics"))
+}
+
+/// A new unguessable feed token.
+pub fn new_token() -> String {
+    let mut bytes = [0; TOKEN_BYTES];
+    getrandom::getrandom(&mut bytes).expect("The OS random number generator should be available");
+
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// The calendar of the user's upcoming posts, or of the guild's when given.
+pub async fn feed<Db: Database, Manager: PostManager<Db> + JoinedManager<Db>>(
+    pool: &Pool<Db>,
+    user: UserId,
+    guild: Option<GuildId>,
+) -> sqlx::Result<String> {
+    match guild {
+        Some(guild) => {
+            let posts = Manager::guild_posts(pool, guild, Utc::now(), None).await?;
+
+            Ok(calendar(
+                "LFG Server Events",
+                posts.iter().map(|post| {
+                    let mut event = CalendarEvent::from(post);
+                    event.guild = event.guild.or(Some(guild));
+                    event
+                }),
+            ))
+        }
+        None => {
+            let rows = Manager::upcoming(pool, user).await?;
+
+            Ok(calendar(
+                "My LFG Events",
+                rows.iter().map(CalendarEvent::from),
+            ))
+        }
+    }
+}
+
+/// A post as a calendar event.
+pub struct CalendarEvent {
+    pub thread: ChannelId,
+    pub guild: Option<GuildId>,
+    pub summary: String,
+    pub description: String,
+    pub start: DateTime<Utc>,
+}
+
+impl CalendarEvent {
+    /// The link to the post's thread, if the guild is known.
+    pub fn url(&self) -> Option<String> {
+        self.guild
+            .map(|guild| format!("https://discord.com/channels/{guild}/{}", self.thread))
+    }
+}
+
+impl From<&PostRow> for CalendarEvent {
+    fn from(post: &PostRow) -> Self {
+        let summary = match post.difficulty() {
+            Some(difficulty) => format!("{} ({difficulty})", post.activity()),
+            None => post.activity().to_string(),
+        };
+
+        let mut description = post.description().trim().to_string();
+        if !description.is_empty() {
+            description.push_str("\n\n");
+        }
+        description.push_str(&format!(
+            "Fireteam: {}/{}",
+            post.fireteam().count(),
+            post.fireteam_size()
+        ));
+
+        Self {
+            thread: post.channel(),
+            guild: post.guild(),
+            summary,
+            description,
+            start: post.start_time,
+        }
+    }
+}
+
+impl From<&JoinedRow> for CalendarEvent {
+    fn from(row: &JoinedRow) -> Self {
+        Self {
+            thread: row.channel_id(),
+            guild: row.guild(),
+            summary: row.activity().to_string(),
+            description: format!("Fireteam: {}/{}", row.fireteam().count(), row.fireteam_size),
+            start: row.start_time,
+        }
+    }
+}
+
+/// The calendar of the events, with the alarms of the post reminders.
+pub fn calendar(name: &str, events: impl IntoIterator<Item = CalendarEvent>) -> String {
+    let stamp = timestamp(Utc::now());
+
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        String::from("PRODID:-//Zayden//LFG//EN"),
+        String::from("CALSCALE:GREGORIAN"),
+        String::from("METHOD:PUBLISH"),
+        format!("X-WR-CALNAME:{}", escape(name)),
+    ];
+
+    for event in events {
+        let mut description = event.description.clone();
+        let url = event.url();
+        if let Some(url) = &url {
+            description.push_str(&format!("\n{url}"));
+        }
+
+        lines.extend([
+            String::from("BEGIN:VEVENT"),
+            format!("UID:lfg-{}@zayden", event.thread),
+            format!("DTSTAMP:{stamp}"),
+            format!("DTSTART:{}", timestamp(event.start)),
+            format!(
+                "DTEND:{}",
+                timestamp(event.start + Duration::hours(EVENT_HOURS))
+            ),
+            format!("SUMMARY:{}", escape(&event.summary)),
+            format!("DESCRIPTION:{}", escape(&description)),
+        ]);
+
+        if let Some(url) = url {
+            lines.push(format!("URL:{url}"));
+        }
+
+        for offset in REMINDER_OFFSETS_MINS {
+            lines.extend([
+                String::from("BEGIN:VALARM"),
+                String::from("ACTION:DISPLAY"),
+                format!("DESCRIPTION:{}", escape(&event.summary)),
+                format!("TRIGGER:{}", trigger(offset)),
+                String::from("END:VALARM"),
+            ]);
+        }
+
+        lines.push(String::from("END:VEVENT"));
+    }
+
+    lines.push(String::from("END:VCALENDAR"));
+
+    lines
+        .iter()
+        .map(|line| fold(line))
+        .map(|line| line + "\r\n")
+        .collect()
+}
+
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// The duration before the start, e.g. `-P7D` or `-PT30M`.
+fn trigger(mins: i64) -> String {
+    if mins % (24 * 60) == 0 {
+        format!("-P{}D", mins / (24 * 60))
+    } else if mins % 60 == 0 {
+        format!("-PT{}H", mins / 60)
+    } else {
+        format!("-PT{mins}M")
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Splits the line into lines of at most [`LINE_LIMIT`] octets, without splitting a character.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut len = 0;
+
+    for c in line.chars() {
+        if len + c.len_utf8() > LINE_LIMIT {
+            folded.push_str("\r\n ");
+            // The leading space counts towards the limit
+            len = 1;
+        }
+
+        folded.push(c);
+        len += c.len_utf8();
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trigger_uses_the_largest_whole_unit() {
+        assert_eq!(trigger(7 * 24 * 60), "-P7D");
+        assert_eq!(trigger(24 * 60), "-P1D");
+        assert_eq!(trigger(2 * 60), "-PT2H");
+        assert_eq!(trigger(30), "-PT30M");
+        assert_eq!(trigger(90), "-PT90M");
+    }
+
+    #[test]
+    fn trigger_of_every_reminder() {
+        let triggers = REMINDER_OFFSETS_MINS.map(trigger);
+
+        assert_eq!(triggers, ["-P7D", "-P1D", "-PT30M"]);
+    }
+
+    #[test]
+    fn escape_special_characters() {
+        assert_eq!(escape("Raid; Normal, 6"), r"Raid\; Normal\, 6");
+        assert_eq!(escape(r"C:\path"), r"C:\\path");
+        assert_eq!(escape("one\ntwo\r\nthree"), r"one\ntwo\nthree");
+    }
+
+    #[test]
+    fn escape_backslashes_first() {
+        // An escaped comma isn't escaped again
+        assert_eq!(escape(r"\,"), r"\\\,");
+    }
+
+    #[test]
+    fn fold_leaves_short_lines() {
+        let line = "a".repeat(LINE_LIMIT);
+
+        assert_eq!(fold(&line), line);
+    }
+
+    #[test]
+    fn fold_long_lines() {
+        let line = "a".repeat(LINE_LIMIT * 2);
+        let folded = fold(&line);
+        let lines = folded.split("\r\n").collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].len(), LINE_LIMIT);
+        assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+        assert!(lines.iter().all(|line| line.len() <= LINE_LIMIT));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn fold_keeps_characters_whole() {
+        // 2 octets each, so the first line ends an octet short of the limit
+        let line = "é".repeat(40);
+        let folded = fold(&line);
+        let lines = folded.split("\r\n").collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].len(), LINE_LIMIT - 1);
+        assert!(lines.iter().all(|line| line.len() <= LINE_LIMIT));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
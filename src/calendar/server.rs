@@ -0,0 +1,152 @@
+//! A minimal HTTP server for the subscribable calendar feeds, at `GET /calendar/{token}.ics`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use sqlx::{Database, Pool};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::sync::Semaphore;
+use tokio::time::timeout;
+
+use crate::{CalendarTokenManager, JoinedManager, PostManager};
+
+use super::{FEED_URL, feed};
+
+/// The longest request head read, in bytes.
+const HEAD_LIMIT: u64 = 8 * 1024;
+/// The most connections served at once. Further connections wait to be accepted.
+const MAX_CONNECTIONS: usize = 64;
+/// How long a connection has to send its request and read the response.
+const CONNECTION_TIMEOUT_SECS: u64 = 10;
+
+/// Serves the feeds on `addr` until it fails to listen.
+///
+/// `public_url` is where the server is reached from outside, e.g. `https://lfg.example.com`,
+/// and is used for the links from `/lfg calendar subscribe`.
+pub async fn serve<
+    Db: Database,
+    Manager: PostManager<Db> + JoinedManager<Db> + CalendarTokenManager<Db> + 'static,
+>(
+    pool: Pool<Db>,
+    addr: impl ToSocketAddrs,
+    public_url: impl Into<String>,
+) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+
+    let public_url = public_url.into();
+    let _ = FEED_URL.set(public_url.trim_end_matches('/').to_string());
+
+    let connections = Arc::new(Semaphore::new(MAX_CONNECTIONS));
+
+    loop {
+        let permit = connections
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("The semaphore is never closed");
+
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                eprintln!("Failed to accept a calendar feed connection: {e:?}");
+                continue;
+            }
+        };
+
+        let pool = pool.clone();
+
+        tokio::spawn(async move {
+            let response = timeout(
+                Duration::from_secs(CONNECTION_TIMEOUT_SECS),
+                respond::<Db, Manager>(stream, &pool),
+            )
+            .await;
+
+            // Slow clients are dropped without a response
+            if let Ok(Err(e)) = response {
+                eprintln!("Failed to serve a calendar feed: {e:?}");
+            }
+
+            drop(permit);
+        });
+    }
+}
+
+async fn respond<
+    Db: Database,
+    Manager: PostManager<Db> + JoinedManager<Db> + CalendarTokenManager<Db>,
+>(
+    mut stream: TcpStream,
+    pool: &Pool<Db>,
+) -> std::io::Result<()> {
+    // Past the limit, reads end as if the client closed the connection
+    let mut reader = BufReader::new((&mut stream).take(HEAD_LIMIT));
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+
+    // Skip the headers, so the connection closes cleanly
+    loop {
+        let mut line = String::new();
+        let n = reader.read_line(&mut line).await?;
+
+        if n == 0 || line.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next(), parts.next());
+
+    let token = path
+        .and_then(|path| path.strip_prefix("/calendar/"))
+        .and_then(|path| path.strip_suffix(".ics"));
+
+    let (status, body) = match (method, token) {
+        (Some("GET"), Some(token)) => match calendar::<Db, Manager>(pool, token).await {
+            Ok(Some(body)) => ("200 OK", body),
+            Ok(None) => ("404 Not Found", String::from("Not Found")),
+            Err(e) => {
+                eprintln!("Failed to load a calendar feed: {e:?}");
+                (
+                    "500 Internal Server Error",
+                    String::from("Internal Server Error"),
+                )
+            }
+        },
+        (Some("GET"), None) => ("404 Not Found", String::from("Not Found")),
+        _ => ("405 Method Not Allowed", String::from("Method Not Allowed")),
+    };
+
+    let content_type = if status.starts_with("200") {
+        "text/calendar; charset=utf-8"
+    } else {
+        "text/plain; charset=utf-8"
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// The token's feed, or `None` if the token doesn't exist or was revoked.
+async fn calendar<
+    Db: Database,
+    Manager: PostManager<Db> + JoinedManager<Db> + CalendarTokenManager<Db>,
+>(
+    pool: &Pool<Db>,
+    token: &str,
+) -> sqlx::Result<Option<String>> {
+    let Some(row) = Manager::token(pool, token).await? else {
+        return Ok(None);
+    };
+
+    feed::<Db, Manager>(pool, row.user(), row.guild())
+        .await
+        .map(Some)
+}
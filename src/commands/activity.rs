@@ -0,0 +1,293 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serenity::all::{
+    CommandInteraction, Context, CreateEmbed, EditInteractionResponse, ForumTag, ForumTagId,
+    GuildChannel, GuildId, ResolvedValue,
+};
+use sqlx::any::AnyQueryResult;
+use sqlx::prelude::FromRow;
+use sqlx::{Database, Pool};
+use zayden_core::parse_options;
+
+use crate::activities::DEFAULT_GAME;
+use crate::locale::{message, message_with};
+use crate::{
+    Activity, ActivityCatalog, ActivityCategory, Error, GameManager, GuildManager, Result,
+};
+
+use super::{Command, is_admin, select_game};
+
+#[async_trait]
+pub trait CustomActivityManager<Db: Database> {
+    async fn activities(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Vec<CustomActivityRow>>;
+
+    /// Inserts the activity, replacing any existing activity with the same name.
+    async fn insert(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        row: CustomActivityRow,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn delete(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        name: &str,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+#[derive(FromRow)]
+pub struct CustomActivityRow {
+    pub game: Option<String>,
+    pub name: String,
+    pub category: String,
+    pub fireteam_size: i16,
+    pub tag_id: Option<i64>,
+}
+
+impl CustomActivityRow {
+    pub fn new(
+        game: impl Into<String>,
+        name: impl Into<String>,
+        category: ActivityCategory,
+        fireteam_size: i16,
+        tag_id: Option<ForumTagId>,
+    ) -> Self {
+        Self {
+            game: Some(game.into()),
+            name: name.into(),
+            category: category.to_string(),
+            fireteam_size,
+            tag_id: tag_id.map(|id| id.get() as i64),
+        }
+    }
+
+    pub fn game(&self) -> &str {
+        self.game.as_deref().unwrap_or(DEFAULT_GAME)
+    }
+
+    pub fn category(&self) -> ActivityCategory {
+        ActivityCategory::from(self.category.clone())
+    }
+
+    pub fn tag_id(&self) -> Option<ForumTagId> {
+        self.tag_id.map(|id| ForumTagId::new(id as u64))
+    }
+}
+
+impl From<&CustomActivityRow> for Activity {
+    fn from(value: &CustomActivityRow) -> Self {
+        Self {
+            name: Cow::Owned(value.name.clone()),
+            category: value.category(),
+            fireteam_size: value.fireteam_size,
+            min_fireteam_size: 1,
+            max_fireteam_size: i16::MAX,
+            aliases: Vec::new(),
+            difficulties: Vec::new(),
+            duration: None,
+            thumbnail: None,
+            colour: None,
+        }
+    }
+}
+
+impl Command {
+    pub async fn activity<
+        Db: Database,
+        Manager: GuildManager<Db> + GameManager<Db> + CustomActivityManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        pool: &Pool<Db>,
+        mut options: HashMap<&str, ResolvedValue<'_>>,
+    ) -> Result<()> {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+
+        if !is_admin(interaction) {
+            return Err(Error::MissingPermissions);
+        }
+
+        let locale = interaction.locale.as_str();
+
+        let response = if let Some(ResolvedValue::SubCommand(options)) = options.remove("add") {
+            add::<Db, Manager>(ctx, pool, guild_id, parse_options(options), locale).await?
+        } else if let Some(ResolvedValue::SubCommand(options)) = options.remove("remove") {
+            remove::<Db, Manager>(pool, guild_id, parse_options(options), locale).await?
+        } else {
+            list::<Db, Manager>(ctx, pool, guild_id, locale).await?
+        };
+
+        interaction.edit_response(ctx, response).await?;
+
+        Ok(())
+    }
+}
+
+async fn add<
+    Db: Database,
+    Manager: GuildManager<Db> + GameManager<Db> + CustomActivityManager<Db>,
+>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    let Some(ResolvedValue::String(name)) = options.remove("name") else {
+        unreachable!("Name is required");
+    };
+
+    let Some(ResolvedValue::String(category)) = options.remove("category") else {
+        unreachable!("Category is required");
+    };
+
+    let Some(ResolvedValue::Integer(fireteam_size)) = options.remove("fireteam_size") else {
+        unreachable!("Fireteam size is required");
+    };
+
+    let games = Manager::enabled(pool, guild_id).await?;
+
+    let game = match options.remove("game") {
+        Some(ResolvedValue::String(id)) => select_game(&games, Some(id))?,
+        _ => select_game(&games, None)?,
+    };
+
+    let category = match game.category(category) {
+        Some(category) => category.clone(),
+        None => ActivityCategory::from(category.trim().to_string()),
+    };
+
+    let tag = match options.remove("tag") {
+        Some(ResolvedValue::String(tag)) => {
+            let forum = lfg_channel::<Db, Manager>(ctx, pool, guild_id).await?;
+
+            let tag = find_tag(&forum.available_tags, tag)
+                .ok_or_else(|| Error::InvalidTag(tag.to_string()))?;
+
+            Some(tag.id)
+        }
+        _ => None,
+    };
+
+    let name = name.trim();
+
+    Manager::insert(
+        pool,
+        guild_id,
+        CustomActivityRow::new(game.id.clone(), name, category, fireteam_size as i16, tag),
+    )
+    .await?;
+    ActivityCatalog::load_custom::<Db, Manager>(pool, guild_id).await?;
+
+    Ok(EditInteractionResponse::new().content(message_with(
+        locale,
+        "activity.added",
+        &[("name", &name)],
+    )))
+}
+
+async fn remove<Db: Database, Manager: CustomActivityManager<Db>>(
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    let Some(ResolvedValue::String(name)) = options.remove("name") else {
+        unreachable!("Name is required");
+    };
+
+    let result = Manager::delete(pool, guild_id, name).await?;
+    ActivityCatalog::load_custom::<Db, Manager>(pool, guild_id).await?;
+
+    let content = if result.rows_affected() == 0 {
+        message_with(locale, "activity.not_found", &[("name", &name)])
+    } else {
+        message_with(locale, "activity.removed", &[("name", &name)])
+    };
+
+    Ok(EditInteractionResponse::new().content(content))
+}
+
+async fn list<Db: Database, Manager: GuildManager<Db> + CustomActivityManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    let activities = Manager::activities(pool, guild_id).await?;
+
+    if activities.is_empty() {
+        return Ok(EditInteractionResponse::new().content(message(locale, "activity.empty")));
+    }
+
+    let forum = lfg_channel::<Db, Manager>(ctx, pool, guild_id).await?;
+
+    let description = activities
+        .iter()
+        .map(|activity| {
+            let tag = activity
+                .tag_id()
+                .and_then(|id| forum.available_tags.iter().find(|tag| tag.id == id))
+                .map(|tag| tag.name.clone())
+                .unwrap_or_else(|| message(locale, "activity.no_tag"));
+
+            let game = ActivityCatalog::game(activity.game())
+                .map(|game| game.name.into_owned())
+                .unwrap_or_else(|| activity.game().to_string());
+
+            let details = message_with(
+                locale,
+                "activity.details",
+                &[
+                    ("game", &game),
+                    ("category", &activity.category()),
+                    ("fireteam_size", &activity.fireteam_size),
+                    ("tag", &tag),
+                ],
+            );
+
+            format!("**{}**\n{details}", activity.name)
+        })
+        .collect::<Vec<_>>();
+
+    let embed = CreateEmbed::new()
+        .title(message(locale, "activity.title"))
+        .description(description.join("\n\n"));
+
+    Ok(EditInteractionResponse::new().embed(embed))
+}
+
+pub(super) async fn lfg_channel<Db: Database, Manager: GuildManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+) -> Result<GuildChannel> {
+    let lfg_guild = Manager::row(pool, guild_id)
+        .await?
+        .ok_or(Error::MissingSetup)?;
+
+    let channel = lfg_guild
+        .channel_id()
+        .to_channel(ctx)
+        .await?
+        .guild()
+        .ok_or(Error::InvalidChannel)?;
+
+    Ok(channel)
+}
+
+pub(super) fn find_tag<'a>(tags: &'a [ForumTag], value: &str) -> Option<&'a ForumTag> {
+    match value.parse::<u64>() {
+        Ok(id) => tags.iter().find(|tag| tag.id.get() == id),
+        Err(_) => tags
+            .iter()
+            .find(|tag| tag.name.eq_ignore_ascii_case(value.trim())),
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use serenity::all::{
+    CommandInteraction, Context, EditInteractionResponse, Mentionable, ResolvedValue,
+};
+use sqlx::{Database, Pool};
+
+use crate::locale::message_with;
+use crate::models::AnnouncementManager;
+use crate::utils::owner_or_moderator;
+use crate::{PostManager, Result};
+
+use super::Command;
+
+/// The `mode` choice that makes a post follow the server's setting.
+pub const SERVER_MODE: &str = "server";
+
+impl Command {
+    pub async fn announcements<Db: Database, Manager: PostManager<Db> + AnnouncementManager<Db>>(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        pool: &Pool<Db>,
+        mut options: HashMap<&str, ResolvedValue<'_>>,
+    ) -> Result<()> {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let thread = match options.remove("thread") {
+            Some(ResolvedValue::Channel(channel)) => channel.id,
+            _ => interaction.channel_id,
+        };
+
+        let owner = Manager::owner(pool, thread).await?;
+        owner_or_moderator(owner, interaction.user.id, interaction.member.as_deref())?;
+
+        let mode = match options.remove("mode") {
+            Some(ResolvedValue::String(mode)) if mode != SERVER_MODE => mode.parse().ok(),
+            _ => None,
+        };
+
+        Manager::save_post_announcements(pool, thread, mode).await?;
+
+        let content = match mode {
+            Some(mode) => message_with(
+                &interaction.locale,
+                "response.announcements",
+                &[
+                    ("thread", &thread.mention()),
+                    ("mode", &mode.name(&interaction.locale)),
+                ],
+            ),
+            None => message_with(
+                &interaction.locale,
+                "response.announcements_server",
+                &[("thread", &thread.mention())],
+            ),
+        };
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().content(content))
+            .await?;
+
+        Ok(())
+    }
+}
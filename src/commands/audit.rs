@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use serenity::all::{
+    CommandInteraction, Context, CreateEmbed, EditInteractionResponse, Mentionable, ResolvedValue,
+};
+use sqlx::{Database, Pool};
+
+use crate::locale::{message, message_with};
+use crate::models::{AuditManager, AuditRow};
+use crate::utils::is_moderator;
+use crate::{Error, PostManager, Result};
+
+use super::Command;
+
+/// The most entries shown, newest first.
+const AUDIT_LIMIT: i64 = 25;
+/// Discord's limit on an embed description.
+const DESCRIPTION_LIMIT: usize = 4096;
+/// The most characters of a before or after value shown, so a long description doesn't crowd
+/// out the other entries.
+const VALUE_LIMIT: usize = 300;
+
+impl Command {
+    pub async fn audit<Db: Database, Manager: PostManager<Db> + AuditManager<Db>>(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        pool: &Pool<Db>,
+        mut options: HashMap<&str, ResolvedValue<'_>>,
+    ) -> Result<()> {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let thread = match options.remove("thread") {
+            Some(ResolvedValue::Channel(channel)) => channel.id,
+            _ => interaction.channel_id,
+        };
+
+        let moderator = is_moderator(interaction.member.as_deref());
+
+        match Manager::owner(pool, thread).await {
+            Ok(owner) if interaction.user.id != owner && !moderator => {
+                return Err(Error::PermissionDenied(owner));
+            }
+            Ok(_) => {}
+            // The log of a deleted post is kept for moderators
+            Err(sqlx::Error::RowNotFound) if moderator => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let rows = Manager::entries(pool, thread, AUDIT_LIMIT).await?;
+
+        let mut description = String::new();
+
+        for line in rows.iter().map(|row| entry(row, &interaction.locale)) {
+            // The first entry always fits, as its values are shortened
+            if !description.is_empty() && description.len() + line.len() + 2 > DESCRIPTION_LIMIT {
+                break;
+            }
+
+            description.push_str(&line);
+            description.push_str("\n\n");
+        }
+
+        if description.is_empty() {
+            description = message(&interaction.locale, "audit.empty");
+        }
+
+        let embed = CreateEmbed::new()
+            .title(message(&interaction.locale, "audit.title"))
+            .description(format!(
+                "{}\n\n{}",
+                thread.mention(),
+                description.trim_end()
+            ));
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn entry(row: &AuditRow, locale: &str) -> String {
+    let action = match row.action() {
+        Some(action) => action.name(locale),
+        None => row.action.clone(),
+    };
+
+    let mut line = format!("<t:{}:f> **{action}**", row.created_at.timestamp());
+
+    if let Some(actor) = row.actor() {
+        line.push(' ');
+        line.push_str(&message_with(
+            locale,
+            "audit.by",
+            &[("user", &actor.mention())],
+        ));
+    }
+
+    if let Some(target) = row.target() {
+        line.push_str(&format!(" → {}", target.mention()));
+    }
+
+    match (&row.before, &row.after) {
+        (Some(before), Some(after)) => {
+            line.push_str(&format!(
+                "\n**{}**\n{}\n**{}**\n{}",
+                message(locale, "audit.before"),
+                shorten(before),
+                message(locale, "audit.after"),
+                shorten(after)
+            ));
+        }
+        (Some(value), None) | (None, Some(value)) => {
+            line.push_str(&format!("\n{}", shorten(value)));
+        }
+        (None, None) => {}
+    }
+
+    line
+}
+
+fn shorten(value: &str) -> String {
+    if value.chars().count() <= VALUE_LIMIT {
+        return value.to_string();
+    }
+
+    let mut value = value.chars().take(VALUE_LIMIT - 1).collect::<String>();
+    value.push('…');
+    value
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use serenity::all::{
+    CommandInteraction, Context, CreateAttachment, EditInteractionResponse, GuildId, ResolvedValue,
+};
+use sqlx::{Database, Pool};
+use zayden_core::parse_options;
+
+use crate::calendar::{feed, feed_url, new_token};
+use crate::locale::{message, message_with};
+use crate::{CalendarTokenManager, CalendarTokenRow, Error, PostManager, Result};
+
+use super::{Command, JoinedManager};
+
+impl Command {
+    pub async fn calendar<
+        Db: Database,
+        Manager: PostManager<Db> + JoinedManager<Db> + CalendarTokenManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        pool: &Pool<Db>,
+        options: HashMap<&str, ResolvedValue<'_>>,
+    ) -> Result<()> {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let Some((name, ResolvedValue::SubCommand(options))) = options.into_iter().next() else {
+            unreachable!("Subcommand is required")
+        };
+        let mut options = parse_options(options);
+
+        let guild = if matches!(options.remove("server"), Some(ResolvedValue::Boolean(true))) {
+            Some(interaction.guild_id.ok_or(Error::MissingGuildId)?)
+        } else {
+            None
+        };
+
+        let response = match name {
+            "export" => export::<Db, Manager>(pool, interaction, guild).await?,
+            "subscribe" => subscribe::<Db, Manager>(pool, interaction, guild).await?,
+            "revoke" => {
+                Manager::revoke_token(pool, interaction.user.id, guild).await?;
+
+                EditInteractionResponse::new()
+                    .content(message(&interaction.locale, "calendar.revoked"))
+            }
+            _ => unreachable!("Invalid subcommand"),
+        };
+
+        interaction.edit_response(ctx, response).await?;
+
+        Ok(())
+    }
+}
+
+async fn export<Db: Database, Manager: PostManager<Db> + JoinedManager<Db>>(
+    pool: &Pool<Db>,
+    interaction: &CommandInteraction,
+    guild: Option<GuildId>,
+) -> Result<EditInteractionResponse> {
+    let ics = feed::<Db, Manager>(pool, interaction.user.id, guild).await?;
+
+    Ok(EditInteractionResponse::new()
+        .content(message(&interaction.locale, "calendar.export"))
+        .new_attachment(CreateAttachment::bytes(ics, "lfg.ics")))
+}
+
+async fn subscribe<Db: Database, Manager: CalendarTokenManager<Db>>(
+    pool: &Pool<Db>,
+    interaction: &CommandInteraction,
+    guild: Option<GuildId>,
+) -> Result<EditInteractionResponse> {
+    let token = new_token();
+
+    let Some(url) = feed_url(&token) else {
+        return Ok(EditInteractionResponse::new()
+            .content(message(&interaction.locale, "calendar.disabled")));
+    };
+
+    Manager::save_token(
+        pool,
+        CalendarTokenRow::new(token, interaction.user.id, guild),
+    )
+    .await?;
+
+    Ok(EditInteractionResponse::new().content(message_with(
+        &interaction.locale,
+        "calendar.subscribe",
+        &[("url", &url)],
+    )))
+}
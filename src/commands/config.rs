@@ -0,0 +1,600 @@
+use std::collections::HashMap;
+
+use serenity::all::{
+    CommandInteraction, Context, CreateActionRow, CreateEmbed, CreateInputText,
+    CreateInteractionResponse, CreateModal, EditInteractionResponse, ForumTag, GuildId,
+    InputTextStyle, Mentionable, ResolvedOption, ResolvedValue,
+};
+use sqlx::{Database, Pool};
+use zayden_core::parse_options;
+
+use crate::activities::resolve;
+use crate::locale::{find_locale, message, message_with};
+use crate::models::tag_mapping::{StatusTag, StatusTagRow};
+use crate::models::{AnnouncementManager, AnnouncementMode, ModLog, ModLogManager};
+use crate::{
+    ActivityCatalog, ActivityCategory, CustomActivityManager, Error, GameManager, GuildManager,
+    LocaleManager, Result, TagMappingManager, TagMappingRow, TemplateManager, ThreadTitleManager,
+    ThreadTitleRow,
+};
+
+use super::activity::{find_tag, lfg_channel};
+use super::{Command, is_admin, select_game};
+
+impl Command {
+    pub async fn config<
+        Db: Database,
+        Manager: GuildManager<Db>
+            + GameManager<Db>
+            + CustomActivityManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>
+            + ModLogManager<Db>
+            + AnnouncementManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        pool: &Pool<Db>,
+        options: HashMap<&str, ResolvedValue<'_>>,
+    ) -> Result<()> {
+        let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+
+        if !is_admin(interaction) {
+            return Err(Error::MissingPermissions);
+        }
+
+        let Some((name, ResolvedValue::SubCommand(options))) = options.into_iter().next() else {
+            unreachable!("Subcommand is required")
+        };
+
+        let change = change(name, &options);
+        let options = parse_options(options);
+
+        // The template editor is a modal, which has to be the first response
+        if name == "template" {
+            template::<Db, Manager>(ctx, interaction, pool, guild_id, options).await?;
+        } else {
+            interaction.defer_ephemeral(ctx).await?;
+
+            let locale = interaction.locale.as_str();
+
+            let response = match name {
+                "tags" => tags::<Db, Manager>(ctx, pool, guild_id, options, locale).await?,
+                "status_tags" => {
+                    status_tags::<Db, Manager>(ctx, pool, guild_id, options, locale).await?
+                }
+                "title" => title::<Db, Manager>(pool, guild_id, options, locale).await?,
+                "language" => language::<Db, Manager>(pool, guild_id, options, locale).await?,
+                "log_channel" => {
+                    log_channel::<Db, Manager>(pool, guild_id, options, locale).await?
+                }
+                "announcements" => {
+                    announcements::<Db, Manager>(pool, guild_id, options, locale).await?
+                }
+                _ => unreachable!("Invalid subcommand"),
+            };
+
+            interaction.edit_response(ctx, response).await?;
+        }
+
+        // Subcommands without options only show the current setting
+        if let Some(change) = change {
+            ModLog::SetupChanged {
+                actor: interaction.user.id,
+                change,
+            }
+            .send::<Db, Manager>(ctx, pool, Some(guild_id))
+            .await;
+        }
+
+        Ok(())
+    }
+}
+
+/// A summary of the subcommand for the log channel, `None` if it was given no options.
+fn change(subcommand: &str, options: &[ResolvedOption<'_>]) -> Option<String> {
+    let values = options
+        .iter()
+        .filter_map(|option| {
+            let value = match &option.value {
+                ResolvedValue::String(value) => value.to_string(),
+                ResolvedValue::Integer(value) => value.to_string(),
+                ResolvedValue::Boolean(value) => value.to_string(),
+                ResolvedValue::Channel(channel) => channel.id.mention().to_string(),
+                ResolvedValue::Role(role) => role.id.mention().to_string(),
+                _ => return None,
+            };
+
+            Some(format!("{}: {value}", option.name))
+        })
+        .collect::<Vec<_>>();
+
+    if values.is_empty() {
+        return None;
+    }
+
+    Some(format!("/lfg config {subcommand} {}", values.join(" ")))
+}
+
+async fn tags<
+    Db: Database,
+    Manager: GuildManager<Db> + GameManager<Db> + CustomActivityManager<Db> + TagMappingManager<Db>,
+>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    let string = |value: Option<ResolvedValue<'_>>| match value {
+        Some(ResolvedValue::String(s)) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        _ => None,
+    };
+
+    let category = string(options.remove("category"));
+    let activity = string(options.remove("activity"));
+    let tags = string(options.remove("tags"));
+
+    let games = Manager::enabled(pool, guild_id).await?;
+    let game = select_game(&games, string(options.remove("game")).as_deref())?;
+
+    let forum = lfg_channel::<Db, Manager>(ctx, pool, guild_id).await?;
+
+    if category.is_none() && activity.is_none() {
+        return list::<Db, Manager>(pool, guild_id, &forum.available_tags, locale).await;
+    }
+
+    let tag_ids = match tags {
+        Some(tags) => {
+            let mut ids = Vec::new();
+
+            for tag in tags.split(',').filter(|tag| !tag.trim().is_empty()) {
+                let tag = find_tag(&forum.available_tags, tag)
+                    .ok_or_else(|| Error::InvalidTag(tag.trim().to_string()))?;
+
+                if !ids.contains(&tag.id) {
+                    ids.push(tag.id);
+                }
+            }
+
+            if ids.len() > 5 {
+                return Err(Error::TagLimit);
+            }
+
+            ids
+        }
+        None => Vec::new(),
+    };
+
+    let (target, row) = match activity {
+        Some(activity) => {
+            let custom = Manager::activities(pool, guild_id).await?;
+            let activities = ActivityCatalog::with_custom(&game.id, &custom);
+
+            let name = resolve(&activities, &activity)
+                .map(|a| a.name.to_string())
+                .unwrap_or(activity);
+
+            (
+                message_with(locale, "config.target_activity", &[("name", &name)]),
+                TagMappingRow::activity(game.id.clone(), name, tag_ids.clone()),
+            )
+        }
+        None => {
+            let category = category.ok_or(Error::MissingCategory)?;
+            let category = match game.category(&category) {
+                Some(category) => category.clone(),
+                None => ActivityCategory::from(category),
+            };
+
+            (
+                message_with(locale, "config.target_category", &[("name", &category)]),
+                TagMappingRow::category(game.id.clone(), &category, tag_ids.clone()),
+            )
+        }
+    };
+
+    if tag_ids.is_empty() {
+        Manager::delete_tag_mapping(
+            pool,
+            guild_id,
+            &row.game,
+            row.category.as_deref(),
+            row.activity.as_deref(),
+        )
+        .await?;
+
+        return Ok(EditInteractionResponse::new().content(message_with(
+            locale,
+            "config.mapping_removed",
+            &[("target", &target)],
+        )));
+    }
+
+    Manager::save_tag_mapping(pool, guild_id, row).await?;
+
+    let names = tag_ids
+        .iter()
+        .filter_map(|id| forum.available_tags.iter().find(|tag| tag.id == *id))
+        .map(|tag| tag.name.as_str())
+        .collect::<Vec<_>>();
+
+    Ok(EditInteractionResponse::new().content(message_with(
+        locale,
+        "config.mapping_saved",
+        &[("target", &target), ("tags", &names.join(", "))],
+    )))
+}
+
+async fn list<Db: Database, Manager: TagMappingManager<Db>>(
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    available: &[ForumTag],
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    let mappings = Manager::tag_mappings(pool, guild_id).await?;
+
+    if mappings.is_empty() {
+        return Ok(EditInteractionResponse::new().content(message(locale, "config.mappings_empty")));
+    }
+
+    let description = mappings
+        .iter()
+        .map(|row| {
+            let target = match (&row.activity, &row.category) {
+                (Some(activity), _) => {
+                    message_with(locale, "config.mapping_activity", &[("activity", activity)])
+                }
+                (None, Some(category)) => {
+                    message_with(locale, "config.mapping_category", &[("category", category)])
+                }
+                (None, None) => message(locale, "config.unknown"),
+            };
+
+            let game = ActivityCatalog::game(&row.game)
+                .map(|game| game.name.into_owned())
+                .unwrap_or_else(|| row.game.clone());
+
+            let tags = row
+                .tags()
+                .map(|id| match available.iter().find(|tag| tag.id == id) {
+                    Some(tag) => tag.name.clone(),
+                    None => message(locale, "config.deleted_tag"),
+                })
+                .collect::<Vec<_>>();
+
+            format!("**{target}** ({game})\n{}", tags.join(", "))
+        })
+        .collect::<Vec<_>>();
+
+    let embed = CreateEmbed::new()
+        .title(message(locale, "config.mappings_title"))
+        .description(description.join("\n\n"));
+
+    Ok(EditInteractionResponse::new().embed(embed))
+}
+
+async fn status_tags<Db: Database, Manager: GuildManager<Db> + TagMappingManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    let forum = lfg_channel::<Db, Manager>(ctx, pool, guild_id).await?;
+
+    let Some(ResolvedValue::String(status)) = options.remove("status") else {
+        let rows = Manager::status_tags(pool, guild_id).await?;
+
+        let description = StatusTag::ALL
+            .iter()
+            .map(|status| {
+                let tag = rows
+                    .iter()
+                    .find(|row| row.status() == Some(*status))
+                    .map(|row| {
+                        match forum
+                            .available_tags
+                            .iter()
+                            .find(|tag| tag.id == row.tag_id())
+                        {
+                            Some(tag) => tag.name.clone(),
+                            None => message(locale, "config.deleted_tag"),
+                        }
+                    })
+                    .unwrap_or_else(|| message(locale, "config.none"));
+
+                format!("**{}**: {tag}", status.name(locale))
+            })
+            .collect::<Vec<_>>();
+
+        let embed = CreateEmbed::new()
+            .title(message(locale, "config.status_title"))
+            .description(description.join("\n"));
+
+        return Ok(EditInteractionResponse::new().embed(embed));
+    };
+
+    let status = status
+        .parse::<StatusTag>()
+        .expect("Status should be one of the choices");
+
+    match options.remove("tag") {
+        Some(ResolvedValue::String(tag)) => {
+            let tag = find_tag(&forum.available_tags, tag)
+                .ok_or_else(|| Error::InvalidTag(tag.to_string()))?;
+
+            Manager::save_status_tag(pool, guild_id, StatusTagRow::new(status, tag.id)).await?;
+
+            Ok(EditInteractionResponse::new().content(message_with(
+                locale,
+                "config.status_saved",
+                &[("tag", &tag.name), ("status", &status.name(locale))],
+            )))
+        }
+        _ => {
+            Manager::delete_status_tag(pool, guild_id, status.id()).await?;
+
+            Ok(EditInteractionResponse::new().content(message_with(
+                locale,
+                "config.status_removed",
+                &[("status", &status.name(locale))],
+            )))
+        }
+    }
+}
+
+async fn title<Db: Database, Manager: ThreadTitleManager<Db>>(
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    if let Some(ResolvedValue::Boolean(true)) = options.remove("disable") {
+        Manager::delete_title_format(pool, guild_id).await?;
+
+        return Ok(EditInteractionResponse::new().content(message(locale, "config.title_disabled")));
+    }
+
+    let format = match options.remove("format") {
+        Some(ResolvedValue::String(format)) => Some(format),
+        _ => None,
+    };
+    let full = match options.remove("full") {
+        Some(ResolvedValue::String(full)) => Some(full),
+        _ => None,
+    };
+
+    let current = Manager::title_format(pool, guild_id).await?;
+
+    if format.is_none() && full.is_none() {
+        let content = match current {
+            Some(row) => message_with(
+                locale,
+                "config.title_current",
+                &[("format", &row.format), ("full", &row.full)],
+            ),
+            None => message(locale, "config.title_none"),
+        };
+
+        return Ok(EditInteractionResponse::new().content(content));
+    }
+
+    let row = ThreadTitleRow::new(
+        format.or(current.as_ref().map(|row| row.format.as_str())),
+        full.or(current.as_ref().map(|row| row.full.as_str())),
+    )?;
+
+    let content = message_with(
+        locale,
+        "config.title_saved",
+        &[("format", &row.format), ("full", &row.full)],
+    );
+
+    Manager::save_title_format(pool, guild_id, row).await?;
+
+    Ok(EditInteractionResponse::new().content(content))
+}
+
+async fn language<Db: Database, Manager: LocaleManager<Db>>(
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    let Some(ResolvedValue::String(language)) = options.remove("language") else {
+        let current = Manager::guild_locale(pool, guild_id).await?;
+
+        let content = match current.as_deref().and_then(find_locale) {
+            Some(code) => message_with(
+                locale,
+                "config.language_current",
+                &[("language", &message(code, "name"))],
+            ),
+            None => message(locale, "config.language_none"),
+        };
+
+        return Ok(EditInteractionResponse::new().content(content));
+    };
+
+    let code = find_locale(language).ok_or_else(|| Error::InvalidLocale(language.to_string()))?;
+
+    Manager::save_guild_locale(pool, guild_id, code).await?;
+
+    Ok(EditInteractionResponse::new().content(message_with(
+        locale,
+        "config.language_saved",
+        &[("language", &message(code, "name"))],
+    )))
+}
+
+async fn log_channel<Db: Database, Manager: ModLogManager<Db>>(
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    if let Some(ResolvedValue::Boolean(true)) = options.remove("disable") {
+        Manager::save_log_channel(pool, guild_id, None).await?;
+
+        return Ok(EditInteractionResponse::new().content(message(locale, "config.log_disabled")));
+    }
+
+    let Some(ResolvedValue::Channel(channel)) = options.remove("channel") else {
+        let content = match Manager::log_channel(pool, guild_id).await? {
+            Some(channel) => message_with(
+                locale,
+                "config.log_current",
+                &[("channel", &channel.mention())],
+            ),
+            None => message(locale, "config.log_none"),
+        };
+
+        return Ok(EditInteractionResponse::new().content(content));
+    };
+
+    Manager::save_log_channel(pool, guild_id, Some(channel.id)).await?;
+
+    Ok(EditInteractionResponse::new().content(message_with(
+        locale,
+        "config.log_saved",
+        &[("channel", &channel.id.mention())],
+    )))
+}
+
+async fn announcements<Db: Database, Manager: AnnouncementManager<Db>>(
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+    locale: &str,
+) -> Result<EditInteractionResponse> {
+    let Some(mode) = (match options.remove("mode") {
+        Some(ResolvedValue::String(mode)) => mode.parse::<AnnouncementMode>().ok(),
+        _ => None,
+    }) else {
+        let mode = Manager::guild_announcements(pool, guild_id)
+            .await?
+            .unwrap_or_default();
+
+        return Ok(EditInteractionResponse::new().content(message_with(
+            locale,
+            "config.announcements_current",
+            &[("mode", &mode.name(locale))],
+        )));
+    };
+
+    Manager::save_guild_announcements(pool, guild_id, mode).await?;
+
+    Ok(EditInteractionResponse::new().content(message_with(
+        locale,
+        "config.announcements_saved",
+        &[("mode", &mode.name(locale))],
+    )))
+}
+
+async fn template<Db: Database, Manager: TemplateManager<Db>>(
+    ctx: &Context,
+    interaction: &CommandInteraction,
+    pool: &Pool<Db>,
+    guild_id: GuildId,
+    mut options: HashMap<&str, ResolvedValue<'_>>,
+) -> Result<()> {
+    if let Some(ResolvedValue::Boolean(true)) = options.remove("reset") {
+        interaction.defer_ephemeral(ctx).await?;
+
+        Manager::delete_guild_template(pool, guild_id).await?;
+
+        interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .content(message(&interaction.locale, "config.template_reset")),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    let row = Manager::guild_template(pool, guild_id).await?;
+
+    let input = |style, id: &str, value: Option<&str>, placeholder: &str| {
+        let label = message(&interaction.locale, &format!("config.template_{id}"));
+        let input = CreateInputText::new(style, label, id).placeholder(placeholder);
+
+        match value {
+            Some(value) => input.value(value),
+            None => input,
+        }
+    };
+
+    let colour = row
+        .as_ref()
+        .and_then(|row| row.colour)
+        .map(|colour| format!("#{:06X}", colour));
+
+    let components = vec![
+        CreateActionRow::InputText(
+            input(
+                InputTextStyle::Short,
+                "title",
+                row.as_ref().map(|row| row.title.as_str()),
+                "{activity} - {start}",
+            )
+            .max_length(256),
+        ),
+        CreateActionRow::InputText(
+            input(
+                InputTextStyle::Paragraph,
+                "description",
+                row.as_ref().map(|row| row.description.as_str()),
+                "{description}\n\nJoined {joined}/{fireteam_size}:\n{fireteam}",
+            )
+            .required(false),
+        ),
+        CreateActionRow::InputText(
+            input(
+                InputTextStyle::Short,
+                "colour",
+                colour.as_deref(),
+                "#2E86C1",
+            )
+            .required(false),
+        ),
+        CreateActionRow::InputText(
+            input(
+                InputTextStyle::Short,
+                "thumbnail",
+                row.as_ref().and_then(|row| row.thumbnail.as_deref()),
+                "https://example.com/image.png",
+            )
+            .required(false),
+        ),
+        CreateActionRow::InputText(
+            input(
+                InputTextStyle::Short,
+                "footer",
+                row.as_ref().and_then(|row| row.footer.as_deref()),
+                "Posted by {owner}",
+            )
+            .required(false)
+            .max_length(2048),
+        ),
+    ];
+
+    interaction
+        .create_response(
+            ctx,
+            CreateInteractionResponse::Modal(
+                CreateModal::new(
+                    "lfg_template",
+                    message(&interaction.locale, "config.template"),
+                )
+                .components(components),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
@@ -6,13 +6,19 @@ use serenity::all::{
 };
 use sqlx::{Database, Pool};
 
+use crate::activities::{DEFAULT_GAME, resolve};
+use crate::locale::message;
 use crate::modals::modal_components;
-use crate::{ACTIVITIES, Result, TimezoneManager};
+use crate::{ActivityCatalog, CustomActivityManager, Error, GameManager, Result, TimezoneManager};
 
 use super::Command;
 
 impl Command {
-    pub async fn create<Db: Database, Manager: TimezoneManager<Db>>(
+    pub async fn create<
+        Db: Database,
+        TzManager: TimezoneManager<Db>,
+        ActivityManager: GameManager<Db> + CustomActivityManager<Db>,
+    >(
         ctx: &Context,
         interaction: &CommandInteraction,
         pool: &Pool<Db>,
@@ -22,30 +28,70 @@ impl Command {
             unreachable!("Activity is required");
         };
 
+        let difficulty = match options.remove("difficulty") {
+            Some(ResolvedValue::String(s)) => s.parse().ok(),
+            _ => None,
+        };
+
         let template = match options.remove("template") {
             Some(ResolvedValue::String(s)) => s.parse().unwrap(),
             _ => 0,
         };
 
-        let timezone = Manager::get(pool, interaction.user.id, &interaction.locale)
-            .await
-            .unwrap();
+        let timezone = TzManager::get(pool, interaction.user.id, &interaction.locale).await?;
         let now = Utc::now().with_timezone(&timezone);
 
-        let fireteam_size = match ACTIVITIES.iter().find(|a| a.name == activity) {
-            Some(activity) => activity.fireteam_size,
-            None => 3,
+        let (games, custom) = match interaction.guild_id {
+            Some(guild_id) => (
+                ActivityManager::enabled(pool, guild_id).await?,
+                ActivityManager::activities(pool, guild_id).await?,
+            ),
+            None => (
+                ActivityCatalog::game(DEFAULT_GAME).into_iter().collect(),
+                Vec::new(),
+            ),
         };
 
-        let row = modal_components(activity, now, fireteam_size, None);
+        let game = match options.remove("game") {
+            Some(ResolvedValue::String(id)) => games
+                .iter()
+                .find(|game| game.id.eq_ignore_ascii_case(id))
+                .ok_or_else(|| Error::InvalidGame(id.to_string()))?,
+            // Without a game, use the first enabled game with a matching activity
+            _ => games
+                .iter()
+                .find(|game| {
+                    resolve(&ActivityCatalog::with_custom(&game.id, &custom), activity).is_some()
+                })
+                .or(games.first())
+                .ok_or(Error::InvalidGame(DEFAULT_GAME.to_string()))?,
+        };
+
+        let fireteam_size =
+            match resolve(&ActivityCatalog::with_custom(&game.id, &custom), activity) {
+                Some(activity) => activity.fireteam_size,
+                None => 3,
+            };
+
+        let row = modal_components(
+            &game.id,
+            activity,
+            now,
+            fireteam_size,
+            None,
+            difficulty,
+            &interaction.locale,
+        );
 
-        let modal =
-            CreateModal::new(format!("lfg_create_{}", template), "Create Event").components(row);
+        let modal = CreateModal::new(
+            format!("lfg_create_{}_{}", template, game.id),
+            message(&interaction.locale, "modal.create"),
+        )
+        .components(row);
 
         interaction
             .create_response(ctx, CreateInteractionResponse::Modal(modal))
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use serenity::all::{
+    CommandInteraction, Context, CreateEmbed, EditInteractionResponse, ResolvedValue,
+};
+use sqlx::{Database, Pool};
+use zayden_core::parse_options;
+
+use crate::locale::{message, message_with};
+use crate::{ActivityCatalog, Error, GameManager, Result};
+
+use super::{Command, is_admin};
+
+impl Command {
+    pub async fn game<Db: Database, Manager: GameManager<Db>>(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        pool: &Pool<Db>,
+        mut options: HashMap<&str, ResolvedValue<'_>>,
+    ) -> Result<()> {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+
+        if !is_admin(interaction) {
+            return Err(Error::MissingPermissions);
+        }
+
+        let mut enabled = Manager::enabled(pool, guild_id)
+            .await?
+            .into_iter()
+            .map(|game| game.id.into_owned())
+            .collect::<Vec<_>>();
+
+        let content = if let Some(ResolvedValue::SubCommand(options)) = options.remove("enable") {
+            let game = game_option(parse_options(options))?;
+
+            if !enabled.contains(&game) {
+                enabled.push(game.clone());
+            }
+
+            Manager::save_games(pool, guild_id, enabled).await?;
+
+            message_with(&interaction.locale, "game.enabled", &[("game", &game)])
+        } else if let Some(ResolvedValue::SubCommand(options)) = options.remove("disable") {
+            let game = game_option(parse_options(options))?;
+
+            enabled.retain(|id| *id != game);
+
+            Manager::save_games(pool, guild_id, enabled).await?;
+
+            message_with(&interaction.locale, "game.disabled", &[("game", &game)])
+        } else {
+            let games = ActivityCatalog::games()
+                .iter()
+                .map(|game| {
+                    let status = if enabled.iter().any(|id| *id == game.id) {
+                        message(&interaction.locale, "game.status_enabled")
+                    } else {
+                        message(&interaction.locale, "game.status_disabled")
+                    };
+                    let activities = message_with(
+                        &interaction.locale,
+                        "game.activities",
+                        &[("count", &game.activities.len())],
+                    );
+
+                    format!("**{}** (`{}`)\n{status} | {activities}", game.name, game.id)
+                })
+                .collect::<Vec<_>>();
+
+            let embed = CreateEmbed::new()
+                .title(message(&interaction.locale, "game.title"))
+                .description(games.join("\n\n"));
+
+            interaction
+                .edit_response(ctx, EditInteractionResponse::new().embed(embed))
+                .await?;
+
+            return Ok(());
+        };
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().content(content))
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn game_option(mut options: HashMap<&str, ResolvedValue<'_>>) -> Result<String> {
+    let Some(ResolvedValue::String(id)) = options.remove("game") else {
+        unreachable!("Game is required");
+    };
+
+    ActivityCatalog::game(id)
+        .map(|game| game.id.into_owned())
+        .ok_or_else(|| Error::InvalidGame(id.to_string()))
+}
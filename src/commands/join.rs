@@ -3,18 +3,39 @@ use std::collections::HashMap;
 use serenity::all::{CommandInteraction, Context, EditInteractionResponse, ResolvedValue};
 use sqlx::{Database, Pool};
 
-use crate::{PostManager, PostRow, Result, Savable, actions};
+use crate::utils::parse_thread;
+use crate::{
+    AnnouncementManager, AuditManager, Error, LocaleManager, PostManager, PostRow, Result, Savable,
+    ScheduleManager, TagMappingManager, TemplateManager, ThreadTitleManager, actions,
+};
 
 use super::Command;
 
 impl Command {
-    pub async fn join<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>>(
+    pub async fn join<
+        Db: Database,
+        Manager: PostManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>
+            + AuditManager<Db>
+            + AnnouncementManager<Db>
+            + ScheduleManager<Db>
+            + Savable<Db, PostRow>,
+    >(
         ctx: &Context,
         interaction: &CommandInteraction,
         pool: &Pool<Db>,
         mut options: HashMap<&str, ResolvedValue<'_>>,
     ) -> Result<()> {
-        interaction.defer_ephemeral(ctx).await.unwrap();
+        if let Some(ResolvedValue::String(thread)) = options.get("thread")
+            && parse_thread(thread).is_none()
+        {
+            return Err(Error::ChannelNotFound);
+        }
+
+        interaction.defer_ephemeral(ctx).await?;
 
         let alternative = match options.remove("alternative") {
             Some(ResolvedValue::Boolean(alt)) => alt,
@@ -25,8 +46,7 @@ impl Command {
 
         interaction
             .edit_response(ctx, EditInteractionResponse::new().content(content))
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
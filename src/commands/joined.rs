@@ -1,26 +1,55 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serenity::all::{
-    ChannelId, CommandInteraction, Context, CreateEmbed, EditInteractionResponse, Mentionable,
+    ButtonStyle, ChannelId, CommandInteraction, Context, CreateActionRow, CreateButton,
+    CreateEmbed, CreateEmbedFooter, EditInteractionResponse, GuildId, Mentionable, ResolvedValue,
     UserId,
 };
 use sqlx::{Database, Pool, prelude::FromRow};
 
+use crate::Result;
+use crate::locale::{message, message_with};
+
 use super::Command;
 
+/// Posts shown on each page.
+const PAGE_SIZE: usize = 8;
+/// How far back the history goes.
+const HISTORY_DAYS: i64 = 30;
+/// The most characters of an activity shown.
+const ACTIVITY_LIMIT: usize = 100;
+/// Discord's limit on an embed field value.
+const FIELD_LIMIT: usize = 1024;
+
 #[async_trait]
 pub trait JoinedManager<Db: Database> {
+    /// The upcoming posts the user owns, joined or is an alternative of.
     async fn upcoming(
         pool: &Pool<Db>,
         user: impl Into<UserId> + Send,
     ) -> sqlx::Result<Vec<JoinedRow>>;
+
+    /// The posts the user owned, joined or was an alternative of that started between `since`
+    /// and now.
+    async fn history(
+        pool: &Pool<Db>,
+        user: impl Into<UserId> + Send,
+        since: DateTime<Utc>,
+    ) -> sqlx::Result<Vec<JoinedRow>>;
 }
 
 #[derive(FromRow)]
 pub struct JoinedRow {
     pub id: i64,
+    /// `None` for posts created before posts were stored with their guild, until the guild is
+    /// next loaded.
+    pub guild_id: Option<i64>,
+    pub owner: i64,
     pub activity: String,
     pub start_time: DateTime<Utc>,
+    pub fireteam_size: i16,
     pub fireteam: Vec<i64>,
 }
 
@@ -29,6 +58,14 @@ impl JoinedRow {
         ChannelId::new(self.id as u64)
     }
 
+    pub fn guild(&self) -> Option<GuildId> {
+        self.guild_id.map(|id| GuildId::new(id as u64))
+    }
+
+    pub fn owner(&self) -> UserId {
+        UserId::new(self.owner as u64)
+    }
+
     pub fn activity(&self) -> &str {
         &self.activity
     }
@@ -42,57 +79,169 @@ impl JoinedRow {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Section {
+    Owned,
+    Joined,
+    Alternative,
+}
+
+impl Section {
+    fn of(row: &JoinedRow, user: UserId) -> Self {
+        if row.owner() == user {
+            Self::Owned
+        } else if row.fireteam().any(|id| id == user) {
+            Self::Joined
+        } else {
+            Self::Alternative
+        }
+    }
+
+    fn title(&self, locale: &str) -> String {
+        let key = match self {
+            Self::Owned => "joined.owned",
+            Self::Joined => "joined.joined",
+            Self::Alternative => "joined.alternative",
+        };
+
+        message(locale, key)
+    }
+}
+
 impl Command {
     pub async fn joined<Db: Database, Manager: JoinedManager<Db>>(
         ctx: &Context,
         interaction: &CommandInteraction,
         pool: &Pool<Db>,
-    ) {
-        interaction.defer_ephemeral(ctx).await.unwrap();
-
-        let posts = Manager::upcoming(pool, interaction.user.id).await.unwrap();
-
-        let (joined, alternative) = posts
-            .into_iter()
-            .partition::<Vec<_>, _>(|row| row.fireteam().any(|user| user == interaction.user.id));
-
-        let mut embed = CreateEmbed::new().title("Joined LFG Events");
-
-        if !joined.is_empty() {
-            let values = joined
-                .into_iter()
-                .map(|row| {
-                    format!(
-                        "{0}\n<t:{1}> (<t:{1}:R>)\n{2}",
-                        row.activity(),
-                        row.timestamp(),
-                        row.channel_id().mention()
-                    )
-                })
-                .collect::<Vec<_>>();
-
-            embed = embed.field("Joined Posts", values.join("\n\n"), false)
+        mut options: HashMap<&str, ResolvedValue<'_>>,
+    ) -> Result<()> {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let history = matches!(
+            options.remove("history"),
+            Some(ResolvedValue::Boolean(true))
+        );
+
+        let (embed, row) =
+            page::<Db, Manager>(pool, interaction.user.id, history, 0, &interaction.locale).await?;
+
+        let mut response = EditInteractionResponse::new().embed(embed);
+        if let Some(row) = row {
+            response = response.components(vec![row]);
+        }
+
+        interaction.edit_response(ctx, response).await?;
+
+        Ok(())
+    }
+}
+
+/// The embed of the page and its page buttons, if there's more than one page.
+pub(crate) async fn page<Db: Database, Manager: JoinedManager<Db>>(
+    pool: &Pool<Db>,
+    user: UserId,
+    history: bool,
+    page: usize,
+    locale: &str,
+) -> Result<(CreateEmbed, Option<CreateActionRow>)> {
+    let (title, mut posts) = if history {
+        let since = Utc::now() - Duration::days(HISTORY_DAYS);
+        let mut posts = Manager::history(pool, user, since).await?;
+        posts.sort_by_key(|row| std::cmp::Reverse(row.start_time));
+        (
+            message_with(locale, "joined.history_title", &[("days", &HISTORY_DAYS)]),
+            posts,
+        )
+    } else {
+        let mut posts = Manager::upcoming(pool, user).await?;
+        posts.sort_by_key(|row| row.start_time);
+        (message(locale, "joined.title"), posts)
+    };
+
+    // Stable, so each section keeps the time order
+    posts.sort_by_key(|row| Section::of(row, user));
+
+    let mut embed = CreateEmbed::new().title(title);
+
+    if posts.is_empty() {
+        let description = if history {
+            message_with(locale, "joined.history_empty", &[("days", &HISTORY_DAYS)])
+        } else {
+            message(locale, "joined.empty")
+        };
+
+        return Ok((embed.description(description), None));
+    }
+
+    let pages = posts.len().div_ceil(PAGE_SIZE);
+    let page = page.min(pages - 1);
+
+    // A section too long for one field carries on in the next
+    let mut fields: Vec<(Section, String)> = Vec::new();
+
+    for row in posts.iter().skip(page * PAGE_SIZE).take(PAGE_SIZE) {
+        let section = Section::of(row, user);
+
+        let mut activity = row
+            .activity()
+            .chars()
+            .take(ACTIVITY_LIMIT)
+            .collect::<String>();
+        if activity.len() < row.activity().len() {
+            activity.push('…');
         }
 
-        if !alternative.is_empty() {
-            let values = alternative
-                .into_iter()
-                .map(|row| {
-                    format!(
-                        "{0}\n<t:{1}> (<t:{1}:R>)\n{2}",
-                        row.activity(),
-                        row.timestamp(),
-                        row.channel_id().mention()
-                    )
-                })
-                .collect::<Vec<_>>();
-
-            embed = embed.field("Alternative Posts", values.join("\n\n"), false)
+        let value = format!(
+            "**{0}** · {1}/{2}\n<t:{3}:f> (<t:{3}:R>) · {4}",
+            activity,
+            row.fireteam().count(),
+            row.fireteam_size,
+            row.timestamp(),
+            row.channel_id().mention()
+        );
+
+        match fields.last_mut() {
+            Some((last, field))
+                if *last == section
+                    && field.chars().count() + value.chars().count() + 2 <= FIELD_LIMIT =>
+            {
+                field.push_str("\n\n");
+                field.push_str(&value);
+            }
+            _ => fields.push((section, value)),
         }
+    }
 
-        interaction
-            .edit_response(ctx, EditInteractionResponse::new().embed(embed))
-            .await
-            .unwrap();
+    for (section, value) in fields {
+        embed = embed.field(section.title(locale), value, false);
     }
+
+    embed = embed.footer(CreateEmbedFooter::new(message_with(
+        locale,
+        "joined.footer",
+        &[
+            ("page", &(page + 1)),
+            ("pages", &pages),
+            ("count", &posts.len()),
+        ],
+    )));
+
+    if pages == 1 {
+        return Ok((embed, None));
+    }
+
+    let history = history as u8;
+
+    let row = CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("lfg_joined_{}_{history}", page.saturating_sub(1)))
+            .label(message(locale, "list.previous"))
+            .style(ButtonStyle::Secondary)
+            .disabled(page == 0),
+        CreateButton::new(format!("lfg_joined_{}_{history}", page + 1))
+            .label(message(locale, "list.next"))
+            .style(ButtonStyle::Secondary)
+            .disabled(page + 1 == pages),
+    ]);
+
+    Ok((embed, Some(row)))
 }
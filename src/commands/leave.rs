@@ -1,26 +1,49 @@
-use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use std::collections::HashMap;
+
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse, ResolvedValue};
 use sqlx::{Database, Pool};
 
-use crate::{PostManager, PostRow, Result, Savable, actions};
+use crate::utils::parse_thread;
+use crate::{
+    AnnouncementManager, AuditManager, Error, LocaleManager, ModLogManager, PostManager, PostRow,
+    Result, Savable, ScheduleManager, TagMappingManager, TemplateManager, ThreadTitleManager,
+    actions,
+};
 
 use super::Command;
 
 impl Command {
-    pub async fn leave<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>>(
+    pub async fn leave<
+        Db: Database,
+        Manager: PostManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>
+            + AuditManager<Db>
+            + AnnouncementManager<Db>
+            + ScheduleManager<Db>
+            + ModLogManager<Db>
+            + Savable<Db, PostRow>,
+    >(
         ctx: &Context,
         interaction: &CommandInteraction,
         pool: &Pool<Db>,
+        options: HashMap<&str, ResolvedValue<'_>>,
     ) -> Result<()> {
-        interaction.defer_ephemeral(ctx).await.unwrap();
+        if let Some(ResolvedValue::String(thread)) = options.get("thread")
+            && parse_thread(thread).is_none()
+        {
+            return Err(Error::ChannelNotFound);
+        }
+
+        interaction.defer_ephemeral(ctx).await?;
 
-        let content = actions::leave::<Db, Manager>(ctx, interaction, pool)
-            .await
-            .unwrap();
+        let content = actions::leave::<Db, Manager>(ctx, interaction, pool).await?;
 
         interaction
             .edit_response(ctx, EditInteractionResponse::new().content(content))
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
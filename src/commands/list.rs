@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use serenity::all::{
+    ButtonStyle, CommandInteraction, Context, CreateActionRow, CreateButton, CreateEmbed,
+    CreateEmbedFooter, EditInteractionResponse, GuildId, Mentionable, MessageId, ResolvedValue,
+    UserId,
+};
+use sqlx::{Database, Pool};
+
+use crate::locale::{message, message_with};
+use crate::templates::TemplateInfo;
+use crate::{ActivityCatalog, Error, PostManager, PostRow, Result, TimezoneManager};
+
+use super::Command;
+
+/// Posts shown on each page.
+const PAGE_SIZE: usize = 10;
+/// How long the page buttons of a list keep working.
+const LIST_TTL_MINS: i64 = 15;
+
+/// The filters of each list message, for its page buttons.
+static LISTS: LazyLock<Mutex<HashMap<MessageId, ListFilters>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Clone)]
+pub(crate) struct ListFilters {
+    guild: GuildId,
+    activity: Option<String>,
+    category: Option<String>,
+    from: DateTime<Utc>,
+    to: Option<DateTime<Utc>>,
+    open: bool,
+    owner: Option<UserId>,
+    created_at: DateTime<Utc>,
+}
+
+impl ListFilters {
+    /// The filters of the list message, if it hasn't expired.
+    pub(crate) fn get(message: MessageId) -> Option<Self> {
+        LISTS.lock().unwrap().get(&message).cloned()
+    }
+
+    fn save(self, message: MessageId) {
+        let mut lists = LISTS.lock().unwrap();
+
+        let now = Utc::now();
+        lists.retain(|_, filters| now - filters.created_at < Duration::minutes(LIST_TTL_MINS));
+        lists.insert(message, self);
+    }
+
+    fn matches(&self, post: &PostRow) -> bool {
+        if let Some(activity) = &self.activity
+            && !post
+                .activity()
+                .to_lowercase()
+                .contains(&activity.to_lowercase())
+            && !self.same_activity(post, activity)
+        {
+            return false;
+        }
+
+        if let Some(category) = &self.category {
+            let matches = post
+                .category()
+                .is_some_and(|post_category| post_category.name().eq_ignore_ascii_case(category));
+
+            if !matches {
+                return false;
+            }
+        }
+
+        if self.open && post.open_slots() == 0 {
+            return false;
+        }
+
+        self.owner.is_none_or(|owner| post.owner() == owner)
+    }
+
+    /// Whether the query and the post's activity resolve to the same catalog activity, so "vog"
+    /// finds posts for Vault of Glass.
+    fn same_activity(&self, post: &PostRow, query: &str) -> bool {
+        let Some(activity) = ActivityCatalog::find_for(Some(self.guild), post.game(), query) else {
+            return false;
+        };
+
+        ActivityCatalog::find_for(Some(self.guild), post.game(), post.activity())
+            .is_some_and(|post_activity| post_activity.name == activity.name)
+    }
+
+    fn summary(&self, locale: &str) -> String {
+        let mut filters = Vec::new();
+
+        if let Some(activity) = &self.activity {
+            filters.push(message_with(
+                locale,
+                "list.activity",
+                &[("activity", activity)],
+            ));
+        }
+        if let Some(category) = &self.category {
+            filters.push(message_with(
+                locale,
+                "list.category",
+                &[("category", category)],
+            ));
+        }
+
+        let from = format!("<t:{}:d>", self.from.timestamp());
+        match self.to {
+            Some(to) => filters.push(message_with(
+                locale,
+                "list.range",
+                &[
+                    ("from", &from),
+                    (
+                        "to",
+                        &format!("<t:{}:d>", (to - Duration::days(1)).timestamp()),
+                    ),
+                ],
+            )),
+            None => filters.push(message_with(locale, "list.from", &[("from", &from)])),
+        }
+
+        if self.open {
+            filters.push(message(locale, "list.open"));
+        }
+        if let Some(owner) = self.owner {
+            filters.push(message_with(
+                locale,
+                "list.owner",
+                &[("owner", &owner.mention())],
+            ));
+        }
+
+        filters.join(" · ")
+    }
+}
+
+impl Command {
+    pub async fn list<Db: Database, TzManager: TimezoneManager<Db>, Manager: PostManager<Db>>(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        pool: &Pool<Db>,
+        mut options: HashMap<&str, ResolvedValue<'_>>,
+    ) -> Result<()> {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let guild = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+
+        let string = |value: Option<ResolvedValue<'_>>| match value {
+            Some(ResolvedValue::String(s)) if !s.trim().is_empty() => Some(s.trim().to_string()),
+            _ => None,
+        };
+
+        let activity = string(options.remove("activity"));
+        let category = string(options.remove("category"));
+        let from = string(options.remove("from"));
+        let to = string(options.remove("to"));
+        let open = matches!(options.remove("open"), Some(ResolvedValue::Boolean(true)));
+        let owner = match options.remove("owner") {
+            Some(ResolvedValue::User(user, _)) => Some(user.id),
+            _ => None,
+        };
+
+        let timezone = if from.is_some() || to.is_some() {
+            TzManager::get(pool, interaction.user.id, &interaction.locale).await?
+        } else {
+            Tz::UTC
+        };
+
+        let from = match from {
+            Some(from) => day_start(timezone, &from)?,
+            None => Utc::now(),
+        };
+        // Until the end of the day
+        let to = match to {
+            Some(to) => Some(day_start(timezone, &to)? + Duration::days(1)),
+            None => None,
+        };
+
+        let filters = ListFilters {
+            guild,
+            activity,
+            category,
+            from,
+            to,
+            open,
+            owner,
+            created_at: Utc::now(),
+        };
+
+        let (embed, row) = page::<Db, Manager>(pool, &filters, 0, &interaction.locale).await?;
+
+        let mut response = EditInteractionResponse::new().embed(embed);
+        if let Some(row) = row {
+            response = response.components(vec![row]);
+        }
+
+        let message = interaction.edit_response(ctx, response).await?;
+
+        filters.save(message.id);
+
+        Ok(())
+    }
+}
+
+/// The embed of the page and its page buttons, if there's more than one page.
+pub(crate) async fn page<Db: Database, Manager: PostManager<Db>>(
+    pool: &Pool<Db>,
+    filters: &ListFilters,
+    page: usize,
+    locale: &str,
+) -> Result<(CreateEmbed, Option<CreateActionRow>)> {
+    let posts = Manager::guild_posts(pool, filters.guild, filters.from, filters.to)
+        .await?
+        .into_iter()
+        .filter(|post| filters.matches(post))
+        .collect::<Vec<_>>();
+
+    let pages = posts.len().div_ceil(PAGE_SIZE).max(1);
+    let page = page.min(pages - 1);
+
+    let mut embed = CreateEmbed::new()
+        .title(message(locale, "list.title"))
+        .description(filters.summary(locale));
+
+    if posts.is_empty() {
+        return Ok((
+            embed.field(
+                message(locale, "list.empty"),
+                message(locale, "list.empty_hint"),
+                false,
+            ),
+            None,
+        ));
+    }
+
+    for post in posts.iter().skip(page * PAGE_SIZE).take(PAGE_SIZE) {
+        let name = match post.difficulty() {
+            Some(difficulty) => format!("{} ({difficulty})", post.activity()),
+            None => post.activity().to_string(),
+        };
+
+        let value = format!(
+            "<t:{0}:f> (<t:{0}:R>)\n{1}/{2} · {3} · [{4}]({5})",
+            post.timestamp(),
+            post.fireteam().count(),
+            post.fireteam_size(),
+            post.owner().mention(),
+            message(locale, "list.jump"),
+            post.message().link(post.channel(), Some(filters.guild))
+        );
+
+        embed = embed.field(name, value, false);
+    }
+
+    embed = embed.footer(CreateEmbedFooter::new(message_with(
+        locale,
+        "list.footer",
+        &[
+            ("page", &(page + 1)),
+            ("pages", &pages),
+            ("count", &posts.len()),
+        ],
+    )));
+
+    if pages == 1 {
+        return Ok((embed, None));
+    }
+
+    let row = CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("lfg_list_{}", page.saturating_sub(1)))
+            .label(message(locale, "list.previous"))
+            .style(ButtonStyle::Secondary)
+            .disabled(page == 0),
+        CreateButton::new(format!("lfg_list_{}", page + 1))
+            .label(message(locale, "list.next"))
+            .style(ButtonStyle::Secondary)
+            .disabled(page + 1 == pages),
+    ]);
+
+    Ok((embed, Some(row)))
+}
+
+fn day_start(timezone: Tz, date: &str) -> Result<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| Error::InvalidDateTime("YYYY-MM-DD".to_string()))?;
+
+    timezone
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+        .earliest()
+        .map(|start| start.with_timezone(&Utc))
+        .ok_or_else(|| Error::InvalidDateTime("YYYY-MM-DD".to_string()))
+}
@@ -1,30 +1,71 @@
+mod activity;
+mod announcements;
+mod audit;
+mod calendar;
+mod config;
 mod create;
+mod game;
 mod join;
-mod joined;
+pub(crate) mod joined;
 mod leave;
+pub(crate) mod list;
 mod setup;
 mod tags;
 mod timezone;
 
+pub use activity::{CustomActivityManager, CustomActivityRow};
+use announcements::SERVER_MODE;
+use chrono::Utc;
 pub use joined::{JoinedManager, JoinedRow};
 use serenity::all::{
-    AutocompleteChoice, AutocompleteOption, CommandInteraction, CommandOptionType, Context,
-    CreateAutocompleteResponse, CreateCommand, CreateCommandOption, CreateInteractionResponse,
-    ResolvedOption, ResolvedValue,
+    AutocompleteChoice, AutocompleteOption, ChannelType, CommandInteraction, CommandOptionType,
+    Context, CreateAutocompleteResponse, CreateCommand, CreateCommandOption,
+    CreateInteractionResponse, ResolvedOption, ResolvedValue,
 };
 pub use setup::SetupManager;
 use sqlx::{Database, Pool};
 use zayden_core::parse_options;
 
-use crate::{ACTIVITIES, PostManager, PostRow, Result, Savable, TimezoneManager};
+use crate::activities::{DEFAULT_GAME, rank, resolve};
+use crate::locale::locales;
+use crate::models::AnnouncementMode;
+use crate::models::tag_mapping::StatusTag;
+use crate::templates::{TemplateInfo, TemplateKind};
+use crate::{
+    ActivityCatalog, AnnouncementManager, AuditManager, CalendarTokenManager, Difficulty, Error,
+    Game, GameManager, GuildManager, LocaleManager, ModLogManager, PostManager, PostRow, Result,
+    Savable, ScheduleManager, TagMappingManager, TemplateManager, ThreadTitleManager,
+    TimezoneManager,
+};
 
 pub struct Command;
 
 impl Command {
     pub async fn lfg<
         Db: Database,
+        GuildHandler: GuildManager<Db>
+            + GameManager<Db>
+            + CustomActivityManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>
+            + ModLogManager<Db>
+            + AnnouncementManager<Db>,
         TzManager: TimezoneManager<Db>,
-        PostHandler: PostManager<Db> + SetupManager<Db> + JoinedManager<Db> + Savable<Db, PostRow>,
+        PostHandler: PostManager<Db>
+            + SetupManager<Db>
+            + JoinedManager<Db>
+            + CalendarTokenManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>
+            + AuditManager<Db>
+            + AnnouncementManager<Db>
+            + ScheduleManager<Db>
+            + ModLogManager<Db>
+            + Savable<Db, PostRow>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -42,12 +83,29 @@ impl Command {
 
         match command.name {
             "setup" => Self::setup::<Db, PostHandler>(ctx, interaction, pool, options).await?,
-            "create" => Self::create::<Db, TzManager>(ctx, interaction, pool, options).await?,
-            "tags" => Self::tags::<Db, PostHandler>(ctx, interaction, pool, options).await?,
+            "create" => {
+                Self::create::<Db, TzManager, GuildHandler>(ctx, interaction, pool, options).await?
+            }
+            "activity" => {
+                Self::activity::<Db, GuildHandler>(ctx, interaction, pool, options).await?
+            }
+            "game" => Self::game::<Db, GuildHandler>(ctx, interaction, pool, options).await?,
+            "config" => Self::config::<Db, GuildHandler>(ctx, interaction, pool, options).await?,
+            "tags" => Self::tags::<Db, PostHandler>(ctx, interaction, pool).await?,
             "join" => Self::join::<Db, PostHandler>(ctx, interaction, pool, options).await?,
-            "leave" => Self::leave::<Db, PostHandler>(ctx, interaction, pool).await?,
-            "joined" => Self::joined::<Db, PostHandler>(ctx, interaction, pool).await,
+            "leave" => Self::leave::<Db, PostHandler>(ctx, interaction, pool, options).await?,
+            "joined" => Self::joined::<Db, PostHandler>(ctx, interaction, pool, options).await?,
+            "calendar" => {
+                Self::calendar::<Db, PostHandler>(ctx, interaction, pool, options).await?
+            }
+            "list" => {
+                Self::list::<Db, TzManager, PostHandler>(ctx, interaction, pool, options).await?
+            }
             "timezone" => Self::timezone::<Db, TzManager>(ctx, interaction, pool, options).await?,
+            "audit" => Self::audit::<Db, PostHandler>(ctx, interaction, pool, options).await?,
+            "announcements" => {
+                Self::announcements::<Db, PostHandler>(ctx, interaction, pool, options).await?
+            }
             _ => unreachable!("Invalid subcommand"),
         }
 
@@ -91,38 +149,287 @@ impl Command {
         .add_sub_option(
             CreateCommandOption::new(
                 CommandOptionType::String,
-                "template",
-                "The embed template for the event",
+                "game",
+                "The game the activity is for",
             )
-            .add_string_choice("Default", "0"),
-        );
+            .set_autocomplete(true),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "difficulty",
+                "The difficulty of the activity",
+            )
+            .set_autocomplete(true),
+        )
+        .add_sub_option(TemplateKind::ALL.into_iter().fold(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "template",
+                "The embed template for the event. Server uses the template from /lfg config template",
+            ),
+            |option, template| option.add_string_choice(template.name(), template.id().to_string()),
+        ));
 
-        let tags = CreateCommandOption::new(
+        let activity = CreateCommandOption::new(
             CommandOptionType::SubCommandGroup,
-            "tags",
-            "Edit the tags for the lfg post",
+            "activity",
+            "Manage the custom activities for this server",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "add",
+                "Add a custom activity",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "name",
+                    "The name of the activity",
+                )
+                .required(true),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "category",
+                    "The category of the activity",
+                )
+                .required(true)
+                .set_autocomplete(true),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "fireteam_size",
+                    "The default fireteam size",
+                )
+                .required(true)
+                .min_int_value(1)
+                .max_int_value(100),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "tag",
+                    "The forum tag to apply to posts for this activity",
+                )
+                .set_autocomplete(true),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "game",
+                    "The game the activity is for",
+                )
+                .set_autocomplete(true),
+            ),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "remove",
+                "Remove a custom activity",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "name",
+                    "The name of the activity",
+                )
+                .required(true)
+                .set_autocomplete(true),
+            ),
         )
         .add_sub_option(CreateCommandOption::new(
             CommandOptionType::SubCommand,
-            "add",
-            "Add tags to the lfg post",
-        ))
+            "list",
+            "List the custom activities",
+        ));
+
+        let game = CreateCommandOption::new(
+            CommandOptionType::SubCommandGroup,
+            "game",
+            "Choose which games this server organises",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "enable", "Enable a game")
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::String, "game", "The game")
+                        .required(true)
+                        .set_autocomplete(true),
+                ),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "disable", "Disable a game")
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::String, "game", "The game")
+                        .required(true)
+                        .set_autocomplete(true),
+                ),
+        )
         .add_sub_option(CreateCommandOption::new(
             CommandOptionType::SubCommand,
-            "remove",
-            "Remove tags from the lfg post",
+            "list",
+            "List the available games",
         ));
 
+        let config = CreateCommandOption::new(
+            CommandOptionType::SubCommandGroup,
+            "config",
+            "Configure the lfg plugin for this server",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "tags",
+                "Choose the forum tags for a category or activity, or list them",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "category",
+                    "The category to set the tags for",
+                )
+                .set_autocomplete(true),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "activity",
+                    "The activity to set the tags for, overriding its category",
+                )
+                .set_autocomplete(true),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "tags",
+                    "Comma separated forum tags. Leave empty to remove the mapping",
+                )
+                .set_autocomplete(true),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "game",
+                    "The game of the category or activity",
+                )
+                .set_autocomplete(true),
+            ),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "status_tags",
+                "Choose the forum tags kept in sync with the state of posts, or list them",
+            )
+            .add_sub_option(StatusTag::ALL.into_iter().fold(
+                CreateCommandOption::new(CommandOptionType::String, "status", "The status"),
+                |option, status| option.add_string_choice(status.to_string(), status.id()),
+            ))
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "tag",
+                    "The forum tag. Leave empty to stop tagging the status",
+                )
+                .set_autocomplete(true),
+            ),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "template",
+                "Edit the server's text template used by the Server template",
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "reset",
+                "Delete the server's template, posts fall back to the default template",
+            )),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "title",
+                "Show the open slots in thread titles, e.g. [3/6]",
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::String,
+                "format",
+                "The prefix, with {joined}, {fireteam_size} and {slots_left}. Default: [{joined}/{fireteam_size}]",
+            ))
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::String,
+                "full",
+                "The prefix once the fireteam is full. Default: FULL",
+            ))
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "disable",
+                "Stop showing the open slots in thread titles",
+            )),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "language",
+                "The language of the posts, announcements and reminders",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::String, "language", "The language")
+                    .set_autocomplete(true),
+            ),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "log_channel",
+                "The channel moderation actions and setting changes are posted to",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::Channel, "channel", "The log channel")
+                    .channel_types(vec![ChannelType::Text]),
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "disable",
+                "Stop posting to the log channel",
+            )),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "announcements",
+                "How joins, leaves and changes are announced in posts",
+            )
+            .add_sub_option(AnnouncementMode::ALL.into_iter().fold(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "mode",
+                    "How changes are announced",
+                ),
+                |option, mode| option.add_string_choice(mode.to_string(), mode.as_str()),
+            )),
+        );
+
+        let tags = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "tags",
+            "Edit the tags for the lfg post",
+        );
+
         let join = CreateCommandOption::new(
             CommandOptionType::SubCommand,
             "join",
             "Join a looking for group post",
         )
-        .add_sub_option(CreateCommandOption::new(
-            CommandOptionType::Channel,
-            "thread",
-            "The LFG thread",
-        ))
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "thread", "The LFG post")
+                .set_autocomplete(true),
+        )
         .add_sub_option(CreateCommandOption::new(
             CommandOptionType::User,
             "guardian",
@@ -139,16 +446,132 @@ impl Command {
             "leave",
             "Leave a looking for group post",
         )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "thread", "The LFG post")
+                .set_autocomplete(true),
+        );
+
+        let joined = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "joined",
+            "View all the posts you own or have joined",
+        )
+        .add_sub_option(CreateCommandOption::new(
+            CommandOptionType::Boolean,
+            "history",
+            "Show your events from the last 30 days instead",
+        ));
+
+        let audit = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "audit",
+            "View the changes made to a post",
+        )
         .add_sub_option(CreateCommandOption::new(
             CommandOptionType::Channel,
             "thread",
             "The LFG thread",
         ));
 
-        let joined = CreateCommandOption::new(
+        let list = CreateCommandOption::new(
             CommandOptionType::SubCommand,
-            "joined",
-            "View all the posts you have joined",
+            "list",
+            "Browse the upcoming LFG posts",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "activity", "Filter by activity")
+                .set_autocomplete(true),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "category", "Filter by category")
+                .set_autocomplete(true),
+        )
+        .add_sub_option(CreateCommandOption::new(
+            CommandOptionType::String,
+            "from",
+            "The first day, in YYYY-MM-DD. Default: now",
+        ))
+        .add_sub_option(CreateCommandOption::new(
+            CommandOptionType::String,
+            "to",
+            "The last day, in YYYY-MM-DD",
+        ))
+        .add_sub_option(CreateCommandOption::new(
+            CommandOptionType::Boolean,
+            "open",
+            "Only posts with open slots",
+        ))
+        .add_sub_option(CreateCommandOption::new(
+            CommandOptionType::User,
+            "owner",
+            "Only posts by this user",
+        ));
+
+        let announcements = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "announcements",
+            "Choose how joins, leaves and changes are announced in a post",
+        )
+        .add_sub_option(
+            AnnouncementMode::ALL
+                .into_iter()
+                .fold(
+                    CreateCommandOption::new(
+                        CommandOptionType::String,
+                        "mode",
+                        "How changes are announced",
+                    )
+                    .required(true),
+                    |option, mode| option.add_string_choice(mode.to_string(), mode.as_str()),
+                )
+                .add_string_choice("Server setting", SERVER_MODE),
+        )
+        .add_sub_option(CreateCommandOption::new(
+            CommandOptionType::Channel,
+            "thread",
+            "The LFG thread",
+        ));
+
+        let calendar = CreateCommandOption::new(
+            CommandOptionType::SubCommandGroup,
+            "calendar",
+            "Add the upcoming posts to your calendar",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "export",
+                "Download your upcoming posts as a calendar file",
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "server",
+                "Download all the upcoming posts in this server instead",
+            )),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "subscribe",
+                "Get a private link that keeps your calendar app up to date",
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "server",
+                "Subscribe to all the upcoming posts in this server instead",
+            )),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "revoke",
+                "Disable your calendar subscription link",
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "server",
+                "Disable the link to this server's posts instead",
+            )),
         );
 
         let timezone = CreateCommandOption::new(
@@ -166,31 +589,224 @@ impl Command {
             .description("Create a looking for group post")
             .add_option(setup)
             .add_option(create)
+            .add_option(activity)
+            .add_option(game)
+            .add_option(config)
             .add_option(tags)
             .add_option(join)
             .add_option(leave)
             .add_option(joined)
             .add_option(timezone)
+            .add_option(list)
+            .add_option(audit)
+            .add_option(announcements)
+            .add_option(calendar)
     }
 
-    pub async fn autocomplete(
+    pub async fn autocomplete<
+        Db: Database,
+        GuildHandler: GuildManager<Db> + GameManager<Db> + CustomActivityManager<Db>,
+        PostHandler: PostManager<Db> + JoinedManager<Db>,
+    >(
         ctx: &Context,
         interaction: &CommandInteraction,
         option: AutocompleteOption<'_>,
+        pool: &Pool<Db>,
     ) -> Result<()> {
         let command = &interaction.data.options().remove(0);
 
         let opt_value = option.value.to_lowercase();
 
-        let filtered = match command.name {
-            "create" => ACTIVITIES
+        let (games, custom) = match interaction.guild_id {
+            Some(guild_id) if matches!(command.name, "create" | "activity" | "config" | "list") => {
+                (
+                    GuildHandler::enabled(pool, guild_id).await?,
+                    GuildHandler::activities(pool, guild_id).await?,
+                )
+            }
+            _ => (
+                ActivityCatalog::game(DEFAULT_GAME).into_iter().collect(),
+                Vec::new(),
+            ),
+        };
+
+        let selected_game = string_option(&command.value, "game")
+            .and_then(|id| games.iter().find(|game| game.id.eq_ignore_ascii_case(id)));
+
+        let filtered = match (command.name, option.name) {
+            ("create" | "activity" | "config", "game") => games
+                .iter()
+                .filter(|game| {
+                    game.name.to_lowercase().contains(&opt_value) || game.id.contains(&opt_value)
+                })
+                .take(25)
+                .map(|game| AutocompleteChoice::new(&*game.name, &*game.id))
+                .collect::<Vec<_>>(),
+
+            ("create", "difficulty") => {
+                let game = selected_game.or(games.first());
+
+                let activities = game
+                    .map(|game| ActivityCatalog::with_custom(&game.id, &custom))
+                    .unwrap_or_default();
+
+                let difficulties = match string_option(&command.value, "activity")
+                    .and_then(|activity| resolve(&activities, activity))
+                {
+                    Some(activity) if !activity.difficulties.is_empty() => {
+                        activity.difficulties.clone()
+                    }
+                    _ => Difficulty::ALL.to_vec(),
+                };
+
+                difficulties
+                    .into_iter()
+                    .map(|difficulty| difficulty.to_string())
+                    .filter(|difficulty| difficulty.to_lowercase().contains(&opt_value))
+                    .map(|difficulty| AutocompleteChoice::new(difficulty.clone(), difficulty))
+                    .collect::<Vec<_>>()
+            }
+
+            ("create", _) | ("config" | "list", "activity") => {
+                let activities = match selected_game {
+                    Some(game) => ActivityCatalog::with_custom(&game.id, &custom),
+                    None => games
+                        .iter()
+                        .flat_map(|game| ActivityCatalog::with_custom(&game.id, &custom))
+                        .collect(),
+                };
+
+                rank(&activities, &opt_value)
+                    .into_iter()
+                    .take(25)
+                    .map(|activity| AutocompleteChoice::new(&*activity.name, &*activity.name))
+                    .collect::<Vec<_>>()
+            }
+
+            ("activity", "name") => custom
                 .iter()
                 .filter(|activity| activity.name.to_lowercase().contains(&opt_value))
                 .take(25)
-                .map(|activity| AutocompleteChoice::new(activity.name, activity.name))
+                .map(|activity| AutocompleteChoice::new(&activity.name, &*activity.name))
                 .collect::<Vec<_>>(),
 
-            "timezone" => chrono_tz::TZ_VARIANTS
+            ("activity" | "config" | "list", "category") => {
+                let Some(game) = selected_game.or(games.first()) else {
+                    return Ok(());
+                };
+
+                let mut categories = game.categories.clone();
+
+                for row in custom.iter().filter(|row| row.game() == game.id) {
+                    if !categories.contains(&row.category()) {
+                        categories.push(row.category());
+                    }
+                }
+
+                categories
+                    .iter()
+                    .filter(|category| category.name().to_lowercase().contains(&opt_value))
+                    .take(25)
+                    .map(|category| AutocompleteChoice::new(category.name(), category.name()))
+                    .collect::<Vec<_>>()
+            }
+
+            ("activity" | "config", "tag") => {
+                let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+                let forum = activity::lfg_channel::<Db, GuildHandler>(ctx, pool, guild_id).await?;
+
+                forum
+                    .available_tags
+                    .iter()
+                    .filter(|tag| tag.name.to_lowercase().contains(&opt_value))
+                    .take(25)
+                    .map(|tag| AutocompleteChoice::new(&tag.name, tag.id.to_string()))
+                    .collect::<Vec<_>>()
+            }
+
+            ("join", "thread") => {
+                let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+
+                PostHandler::guild_posts(pool, guild_id, Utc::now(), None)
+                    .await?
+                    .into_iter()
+                    .filter(|post| {
+                        post.open_slots() > 0 && post.activity().to_lowercase().contains(&opt_value)
+                    })
+                    .take(25)
+                    .map(|post| {
+                        let name = format!(
+                            "{} · {} · {}/{}",
+                            post.activity(),
+                            post.start_time.format("%d %b %H:%M UTC"),
+                            post.fireteam().count(),
+                            post.fireteam_size()
+                        );
+                        AutocompleteChoice::new(choice_name(name), post.channel().to_string())
+                    })
+                    .collect::<Vec<_>>()
+            }
+
+            ("leave", "thread") => PostHandler::upcoming(pool, interaction.user.id)
+                .await?
+                .into_iter()
+                .filter(|row| {
+                    row.guild() == interaction.guild_id
+                        && row.activity().to_lowercase().contains(&opt_value)
+                })
+                .take(25)
+                .map(|row| {
+                    let name = format!(
+                        "{} · {}",
+                        row.activity(),
+                        row.start_time.format("%d %b %H:%M UTC")
+                    );
+                    AutocompleteChoice::new(choice_name(name), row.channel_id().to_string())
+                })
+                .collect::<Vec<_>>(),
+
+            ("config", "language") => locales()
+                .filter(|(code, name)| {
+                    code.to_lowercase().contains(&opt_value)
+                        || name.to_lowercase().contains(&opt_value)
+                })
+                .take(25)
+                .map(|(code, name)| AutocompleteChoice::new(format!("{name} ({code})"), code))
+                .collect::<Vec<_>>(),
+
+            ("config", "tags") => {
+                let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+                let forum = activity::lfg_channel::<Db, GuildHandler>(ctx, pool, guild_id).await?;
+
+                // Complete the last tag in the comma separated list
+                let (previous, current) = match option.value.rsplit_once(',') {
+                    Some((previous, current)) => (format!("{previous}, "), current),
+                    None => (String::new(), option.value),
+                };
+                let current = current.trim().to_lowercase();
+
+                forum
+                    .available_tags
+                    .iter()
+                    .filter(|tag| tag.name.to_lowercase().contains(&current))
+                    .take(25)
+                    .map(|tag| {
+                        let value = format!("{previous}{}", tag.name);
+                        AutocompleteChoice::new(value.clone(), value)
+                    })
+                    .collect::<Vec<_>>()
+            }
+
+            ("game", _) => ActivityCatalog::games()
+                .iter()
+                .filter(|game| {
+                    game.name.to_lowercase().contains(&opt_value) || game.id.contains(&opt_value)
+                })
+                .take(25)
+                .map(|game| AutocompleteChoice::new(&*game.name, &*game.id))
+                .collect::<Vec<_>>(),
+
+            ("timezone", _) => chrono_tz::TZ_VARIANTS
                 .iter()
                 .filter(|tz| tz.name().to_lowercase().contains(&opt_value))
                 .take(25)
@@ -206,9 +822,57 @@ impl Command {
                     CreateAutocompleteResponse::new().set_choices(filtered),
                 ),
             )
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
 }
+
+fn is_admin(interaction: &CommandInteraction) -> bool {
+    interaction
+        .member
+        .as_ref()
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.manage_guild())
+}
+
+/// The game with the given id, or the first enabled game.
+fn select_game<'a>(games: &'a [Game], id: Option<&str>) -> Result<&'a Game> {
+    match id {
+        Some(id) => games
+            .iter()
+            .find(|game| game.id.eq_ignore_ascii_case(id))
+            .ok_or_else(|| Error::InvalidGame(id.to_string())),
+        None => games
+            .first()
+            .ok_or_else(|| Error::InvalidGame(DEFAULT_GAME.to_string())),
+    }
+}
+
+/// The value of a string option in the subcommand, looking through subcommand groups.
+fn string_option<'a>(value: &ResolvedValue<'a>, name: &str) -> Option<&'a str> {
+    match value {
+        ResolvedValue::SubCommandGroup(options) => options
+            .first()
+            .and_then(|option| string_option(&option.value, name)),
+        ResolvedValue::SubCommand(options) => options
+            .iter()
+            .find(|option| option.name == name)
+            .and_then(|option| match option.value {
+                ResolvedValue::String(value) => Some(value),
+                _ => None,
+            }),
+        _ => None,
+    }
+}
+
+/// The name cut to Discord's limit on the name of an autocomplete choice.
+fn choice_name(name: String) -> String {
+    if name.chars().count() <= 100 {
+        return name;
+    }
+
+    let mut name = name.chars().take(99).collect::<String>();
+    name.push('…');
+    name
+}
@@ -2,11 +2,14 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use serenity::all::{
-    ChannelId, CommandInteraction, Context, EditInteractionResponse, GuildId, ResolvedValue, RoleId,
+    ChannelId, CommandInteraction, Context, EditInteractionResponse, GuildId, Mentionable,
+    ResolvedValue, RoleId,
 };
 use sqlx::any::AnyQueryResult;
 use sqlx::{Database, Pool};
 
+use crate::locale::{LocaleManager, message};
+use crate::models::{ModLog, ModLogManager};
 use crate::{Error, Result};
 
 use super::Command;
@@ -22,13 +25,16 @@ pub trait SetupManager<Db: Database> {
 }
 
 impl Command {
-    pub async fn setup<Db: Database, Manager: SetupManager<Db>>(
+    pub async fn setup<
+        Db: Database,
+        Manager: SetupManager<Db> + ModLogManager<Db> + LocaleManager<Db>,
+    >(
         ctx: &Context,
         interaction: &CommandInteraction,
         pool: &Pool<Db>,
         mut options: HashMap<&str, ResolvedValue<'_>>,
     ) -> Result<()> {
-        interaction.defer_ephemeral(ctx).await.unwrap();
+        interaction.defer_ephemeral(ctx).await?;
 
         let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
 
@@ -41,17 +47,27 @@ impl Command {
             _ => None,
         };
 
-        Manager::insert(pool, guild_id, channel.id, role)
-            .await
-            .unwrap();
+        Manager::insert(pool, guild_id, channel.id, role).await?;
 
         interaction
             .edit_response(
                 ctx,
-                EditInteractionResponse::new().content("LFG plugin has been setup"),
+                EditInteractionResponse::new()
+                    .content(message(&interaction.locale, "response.setup")),
             )
-            .await
-            .unwrap();
+            .await?;
+
+        let mut change = format!("/lfg setup channel: {}", channel.id.mention());
+        if let Some(role) = role {
+            change.push_str(&format!(" role: {}", role.mention()));
+        }
+
+        ModLog::SetupChanged {
+            actor: interaction.user.id,
+            change,
+        }
+        .send::<Db, Manager>(ctx, pool, Some(guild_id))
+        .await;
 
         Ok(())
     }
@@ -1,11 +1,8 @@
-use std::collections::HashMap;
-
-use serenity::all::{
-    CommandInteraction, Context, CreateSelectMenu, CreateSelectMenuKind, CreateSelectMenuOption,
-    EditInteractionResponse, GuildChannel, ResolvedValue,
-};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
 use sqlx::{Database, Pool};
 
+use crate::components::tags::{forum, tag_editor};
+use crate::utils::owner_or_moderator;
 use crate::{Error, PostManager, Result};
 
 use super::Command;
@@ -15,100 +12,43 @@ impl Command {
         ctx: &Context,
         interaction: &CommandInteraction,
         pool: &Pool<Db>,
-        options: HashMap<&str, ResolvedValue<'_>>,
     ) -> Result<()> {
-        interaction.defer_ephemeral(ctx).await.unwrap();
+        interaction.defer_ephemeral(ctx).await?;
 
-        let post_owner = Manager::owner(pool, interaction.channel_id).await.unwrap();
+        let post_owner = Manager::owner(pool, interaction.channel_id).await?;
 
-        if post_owner != interaction.user.id {
-            return Err(Error::PermissionDenied(post_owner));
-        }
+        // The override is logged once a change is saved, by the editor
+        owner_or_moderator(
+            post_owner,
+            interaction.user.id,
+            interaction.member.as_deref(),
+        )?;
 
-        let thread_channel = interaction
+        let thread = interaction
             .channel_id
             .to_channel(ctx)
-            .await
-            .unwrap()
-            .guild()
-            .unwrap();
-
-        let forum_channel = thread_channel
-            .parent_id
-            .unwrap()
-            .to_channel(ctx)
-            .await
-            .unwrap()
+            .await?
             .guild()
-            .unwrap();
-
-        if options.contains_key("add") {
-            add_tags(ctx, interaction, forum_channel, thread_channel)
-                .await
-                .unwrap();
-        } else if options.contains_key("remove") {
-            remove_tags(ctx, interaction, forum_channel, thread_channel)
-                .await
-                .unwrap();
-        }
+            .ok_or(Error::InvalidChannel)?;
+
+        let forum = forum(ctx, &thread).await?;
+
+        let (content, components) = tag_editor(
+            &forum.available_tags,
+            &thread.applied_tags,
+            0,
+            &interaction.locale,
+        )?;
+
+        interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .content(content)
+                    .components(components),
+            )
+            .await?;
 
         Ok(())
     }
 }
-async fn add_tags(
-    ctx: &Context,
-    interaction: &CommandInteraction,
-    forum_channel: GuildChannel,
-    thread_channel: GuildChannel,
-) -> Result<()> {
-    let options = forum_channel
-        .available_tags
-        .into_iter()
-        .filter(|tag| !thread_channel.applied_tags.contains(&tag.id))
-        .map(|tag| CreateSelectMenuOption::new(tag.name, tag.id.to_string()))
-        .collect::<Vec<_>>();
-
-    let max_values = options.len() as u8;
-
-    interaction
-        .edit_response(
-            ctx,
-            EditInteractionResponse::new().select_menu(
-                CreateSelectMenu::new("lfg_tags_add", CreateSelectMenuKind::String { options })
-                    .max_values(max_values),
-            ),
-        )
-        .await
-        .unwrap();
-
-    Ok(())
-}
-
-async fn remove_tags(
-    ctx: &Context,
-    interaction: &CommandInteraction,
-    forum_channel: GuildChannel,
-    thread_channel: GuildChannel,
-) -> Result<()> {
-    let options = forum_channel
-        .available_tags
-        .into_iter()
-        .filter(|tag| thread_channel.applied_tags.contains(&tag.id))
-        .map(|tag| CreateSelectMenuOption::new(tag.name, tag.id.to_string()))
-        .collect::<Vec<_>>();
-
-    let max_values = options.len() as u8;
-
-    interaction
-        .edit_response(
-            ctx,
-            EditInteractionResponse::new().select_menu(
-                CreateSelectMenu::new("lfg_tags_remove", CreateSelectMenuKind::String { options })
-                    .max_values(max_values),
-            ),
-        )
-        .await
-        .unwrap();
-
-    Ok(())
-}
@@ -5,7 +5,8 @@ use chrono_tz::Tz;
 use serenity::all::{CommandInteraction, Context, EditInteractionResponse, ResolvedValue};
 use sqlx::{Database, Pool};
 
-use crate::{Result, TimezoneManager};
+use crate::locale::message_with;
+use crate::{Error, Result, TimezoneManager};
 
 use super::Command;
 
@@ -16,24 +17,26 @@ impl Command {
         pool: &Pool<Db>,
         mut options: HashMap<&str, ResolvedValue<'_>>,
     ) -> Result<()> {
-        interaction.defer_ephemeral(ctx).await.unwrap();
+        interaction.defer_ephemeral(ctx).await?;
 
         let Some(ResolvedValue::String(region)) = options.remove("region") else {
             unreachable!("Region is required");
         };
 
-        let tz = Tz::from_str(region).unwrap();
+        let tz = Tz::from_str(region).map_err(|_| Error::InvalidTimezone(region.to_string()))?;
 
-        Manager::save(pool, interaction.user.id, tz).await.unwrap();
+        Manager::save(pool, interaction.user.id, tz).await?;
 
         interaction
             .edit_response(
                 ctx,
-                EditInteractionResponse::new()
-                    .content(format!("Your timezone has been set to {}", tz.name())),
+                EditInteractionResponse::new().content(message_with(
+                    &interaction.locale,
+                    "response.timezone_set",
+                    &[("timezone", &tz.name())],
+                )),
             )
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
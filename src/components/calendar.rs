@@ -0,0 +1,41 @@
+use serenity::all::{
+    ComponentInteraction, Context, CreateAttachment, CreateInteractionResponse,
+    CreateInteractionResponseMessage,
+};
+use sqlx::{Database, Pool};
+
+use crate::calendar::{CalendarEvent, calendar};
+use crate::locale::message;
+use crate::utils::component_thread;
+use crate::{PostManager, Result};
+
+use super::Components;
+
+impl Components {
+    pub async fn calendar<Db: Database, Manager: PostManager<Db>>(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let post = Manager::row(pool, component_thread(interaction)).await?;
+
+        let mut event = CalendarEvent::from(&post);
+        event.guild = event.guild.or(interaction.guild_id);
+
+        let ics = calendar(&event.summary.clone(), [event]);
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(message(&interaction.locale, "calendar.event"))
+                        .add_file(CreateAttachment::bytes(ics, "lfg.ics"))
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use serenity::all::{ComponentInteraction, Context, CreateInteractionResponse, CreateModal};
 use sqlx::{Database, Pool};
 
+use crate::locale::message;
 use crate::modals::modal_components;
 use crate::{Error, Result};
 
@@ -12,27 +13,35 @@ impl Components {
         interaction: &ComponentInteraction,
         pool: &Pool<Db>,
     ) -> Result<()> {
-        let post = Manager::edit_row(pool, interaction.message.id)
-            .await
-            .unwrap();
+        let post = Manager::edit_row(pool, interaction.message.id).await?;
 
         if interaction.user.id != post.owner() {
             return Err(Error::PermissionDenied(post.owner()));
         }
 
         let row = modal_components(
+            post.game(),
             &post.activity,
             post.start_time(),
             post.fireteam_size,
             Some(&post.description),
+            post.difficulty(),
+            &interaction.locale,
         );
 
-        let modal = CreateModal::new("lfg_create", "Copy Event").components(row);
+        let modal = CreateModal::new(
+            format!(
+                "lfg_create_{}_{}",
+                post.template.unwrap_or_default(),
+                post.game()
+            ),
+            message(&interaction.locale, "modal.copy"),
+        )
+        .components(row);
 
         interaction
             .create_response(ctx, CreateInteractionResponse::Modal(modal))
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
@@ -1,30 +1,43 @@
 use serenity::all::{ComponentInteraction, Context, CreateInteractionResponse};
 use sqlx::{Database, Pool};
 
-use crate::{Error, PostManager, Result, actions};
+use crate::utils::owner_or_moderator;
+use crate::{
+    AuditManager, LocaleManager, ModLogManager, PostManager, Result, ScheduleManager, actions,
+};
 
 use super::Components;
 
 impl Components {
-    pub async fn delete<Db: Database, Manager: PostManager<Db>>(
+    pub async fn delete<
+        Db: Database,
+        Manager: PostManager<Db>
+            + AuditManager<Db>
+            + ModLogManager<Db>
+            + ScheduleManager<Db>
+            + LocaleManager<Db>,
+    >(
         ctx: &Context,
         interaction: &ComponentInteraction,
         pool: &Pool<Db>,
     ) -> Result<()> {
         let owner = Manager::owner(pool, interaction.channel_id).await?;
 
-        if interaction.user.id != owner {
-            return Err(Error::PermissionDenied(owner));
-        }
+        // Moderator deletions are logged with the deleted post
+        owner_or_moderator(owner, interaction.user.id, interaction.member.as_ref())?;
 
-        actions::delete::<Db, Manager>(ctx, interaction.channel_id, pool)
-            .await
-            .unwrap();
+        actions::delete::<Db, Manager>(
+            ctx,
+            interaction.channel_id,
+            pool,
+            interaction.guild_id,
+            Some(interaction.user.id),
+        )
+        .await?;
 
         interaction
             .create_response(ctx, CreateInteractionResponse::Acknowledge)
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
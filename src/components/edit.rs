@@ -7,8 +7,11 @@ use serenity::all::{
 use sqlx::prelude::FromRow;
 use sqlx::{Database, Pool};
 
+use crate::activities::DEFAULT_GAME;
+use crate::locale::message;
 use crate::modals::modal_components;
-use crate::{Error, Result};
+use crate::utils::owner_or_moderator;
+use crate::{Difficulty, Result};
 
 use super::Components;
 
@@ -20,7 +23,10 @@ pub trait EditManager<Db: Database> {
 #[derive(FromRow)]
 pub struct EditRow {
     pub owner: i64,
+    pub game: Option<String>,
     pub activity: String,
+    pub difficulty: Option<String>,
+    pub template: Option<i16>,
     pub start_time: DateTime<Utc>,
     pub description: String,
     pub fireteam_size: i16,
@@ -32,6 +38,14 @@ impl EditRow {
         UserId::new(self.owner as u64)
     }
 
+    pub fn game(&self) -> &str {
+        self.game.as_deref().unwrap_or(DEFAULT_GAME)
+    }
+
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        self.difficulty.as_deref().and_then(|d| d.parse().ok())
+    }
+
     pub fn start_time(&self) -> DateTime<Tz> {
         let tz = match self.timezone.as_deref() {
             Some(tz) => tz.parse().unwrap_or(Tz::UTC),
@@ -48,27 +62,30 @@ impl Components {
         interaction: &ComponentInteraction,
         pool: &Pool<Db>,
     ) -> Result<()> {
-        let post = Manager::edit_row(pool, interaction.message.id)
-            .await
-            .unwrap();
+        let post = Manager::edit_row(pool, interaction.message.id).await?;
 
-        if interaction.user.id != post.owner() {
-            return Err(Error::PermissionDenied(post.owner()));
-        }
+        owner_or_moderator(
+            post.owner(),
+            interaction.user.id,
+            interaction.member.as_ref(),
+        )?;
 
         let row = modal_components(
+            post.game(),
             &post.activity,
             post.start_time(),
             post.fireteam_size,
             Some(&post.description),
+            post.difficulty(),
+            &interaction.locale,
         );
 
-        let modal = CreateModal::new("lfg_edit", "Edit Event").components(row);
+        let modal = CreateModal::new("lfg_edit", message(&interaction.locale, "modal.edit"))
+            .components(row);
 
         interaction
             .create_response(ctx, CreateInteractionResponse::Modal(modal))
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
@@ -0,0 +1,49 @@
+use serenity::all::{
+    ComponentInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+};
+use sqlx::{Database, Pool};
+
+use crate::commands::joined::page;
+use crate::{JoinedManager, Result};
+
+use super::Components;
+
+impl Components {
+    /// Shows another page of a `/lfg joined` message.
+    pub async fn joined<Db: Database, Manager: JoinedManager<Db>>(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        // lfg_joined_{page}_{history}
+        let mut custom_id = interaction.data.custom_id.split('_').skip(2);
+
+        let page_index = custom_id
+            .next()
+            .and_then(|page| page.parse().ok())
+            .unwrap_or_default();
+        let history = custom_id.next() == Some("1");
+
+        let (embed, row) = page::<Db, Manager>(
+            pool,
+            interaction.user.id,
+            history,
+            page_index,
+            &interaction.locale,
+        )
+        .await?;
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(embed)
+                        .components(row.into_iter().collect()),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
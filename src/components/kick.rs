@@ -3,8 +3,13 @@ use serenity::all::{CreateInteractionResponse, CreateSelectMenu, CreateSelectMen
 use sqlx::Database;
 use sqlx::Pool;
 
+use crate::locale::message;
 use crate::models::post::PostManager;
-use crate::{Error, PostRow, Savable};
+use crate::utils::owner_or_moderator;
+use crate::{
+    AnnouncementManager, AuditManager, LocaleManager, ModLogManager, PostRow, Savable,
+    ScheduleManager, TagMappingManager, TemplateManager, ThreadTitleManager,
+};
 use crate::{Result, actions};
 
 use super::Components;
@@ -15,11 +20,10 @@ impl Components {
         interaction: &ComponentInteraction,
         pool: &Pool<Db>,
     ) -> Result<()> {
-        let owner = Manager::owner(pool, interaction.channel_id).await.unwrap();
+        let owner = Manager::owner(pool, interaction.channel_id).await?;
 
-        if interaction.user.id != owner {
-            return Err(Error::PermissionDenied(owner));
-        }
+        // Moderator kicks are logged when the kick is made
+        owner_or_moderator(owner, interaction.user.id, interaction.member.as_ref())?;
 
         let select_menu = CreateSelectMenu::new(
             "lfg_kick_menu",
@@ -33,13 +37,12 @@ impl Components {
                 ctx,
                 CreateInteractionResponse::Message(
                     CreateInteractionResponseMessage::new()
-                        .content("Select the user you want to kick")
+                        .content(message(&interaction.locale, "response.kick"))
                         .select_menu(select_menu)
                         .ephemeral(true),
                 ),
             )
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
@@ -48,19 +51,36 @@ impl Components {
 pub struct KickComponent;
 
 impl KickComponent {
-    pub async fn run<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>>(
+    pub async fn run<
+        Db: Database,
+        Manager: PostManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>
+            + AuditManager<Db>
+            + AnnouncementManager<Db>
+            + ScheduleManager<Db>
+            + ModLogManager<Db>
+            + Savable<Db, PostRow>,
+    >(
         ctx: &Context,
         interaction: &ComponentInteraction,
         pool: &Pool<Db>,
     ) -> Result<()> {
-        actions::leave::<Db, Manager>(ctx, interaction, pool)
-            .await
-            .unwrap();
+        let content = actions::leave::<Db, Manager>(ctx, interaction, pool).await?;
 
+        // Replaces the kick menu with the result
         interaction
-            .create_response(ctx, CreateInteractionResponse::Acknowledge)
-            .await
-            .unwrap();
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(Vec::new()),
+                ),
+            )
+            .await?;
 
         Ok(())
     }
@@ -1,24 +1,38 @@
-use serenity::all::{ComponentInteraction, Context, CreateInteractionResponse};
+use serenity::all::{ComponentInteraction, Context, EditInteractionResponse};
 use sqlx::{Database, Pool};
 
-use crate::{PostManager, PostRow, Result, Savable, actions};
+use crate::{
+    AnnouncementManager, AuditManager, LocaleManager, ModLogManager, PostManager, PostRow, Result,
+    Savable, ScheduleManager, TagMappingManager, TemplateManager, ThreadTitleManager, actions,
+};
 
 use super::Components;
 
 impl Components {
-    pub async fn leave<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>>(
+    pub async fn leave<
+        Db: Database,
+        Manager: PostManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>
+            + AuditManager<Db>
+            + AnnouncementManager<Db>
+            + ScheduleManager<Db>
+            + ModLogManager<Db>
+            + Savable<Db, PostRow>,
+    >(
         ctx: &Context,
         interaction: &ComponentInteraction,
         pool: &Pool<Db>,
     ) -> Result<()> {
-        actions::leave::<Db, Manager>(ctx, interaction, pool)
-            .await
-            .unwrap();
+        interaction.defer_ephemeral(ctx).await?;
+
+        let content = actions::leave::<Db, Manager>(ctx, interaction, pool).await?;
 
         interaction
-            .create_response(ctx, CreateInteractionResponse::Acknowledge)
-            .await
-            .unwrap();
+            .edit_response(ctx, EditInteractionResponse::new().content(content))
+            .await?;
 
         Ok(())
     }
@@ -0,0 +1,48 @@
+use serenity::all::{
+    ComponentInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
+};
+use sqlx::{Database, Pool};
+
+use crate::commands::list::{ListFilters, page};
+use crate::locale::message;
+use crate::{PostManager, Result};
+
+use super::Components;
+
+impl Components {
+    /// Shows another page of a `/lfg list` message.
+    pub async fn list<Db: Database, Manager: PostManager<Db>>(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        // lfg_list_{page}
+        let page_index = interaction
+            .data
+            .custom_id
+            .rsplit('_')
+            .next()
+            .and_then(|page| page.parse().ok())
+            .unwrap_or_default();
+
+        let response = match ListFilters::get(interaction.message.id) {
+            Some(filters) => {
+                let (embed, row) =
+                    page::<Db, Manager>(pool, &filters, page_index, &interaction.locale).await?;
+
+                CreateInteractionResponseMessage::new()
+                    .embed(embed)
+                    .components(row.into_iter().collect())
+            }
+            None => CreateInteractionResponseMessage::new()
+                .content(message(&interaction.locale, "list.expired"))
+                .components(Vec::new()),
+        };
+
+        interaction
+            .create_response(ctx, CreateInteractionResponse::UpdateMessage(response))
+            .await?;
+
+        Ok(())
+    }
+}
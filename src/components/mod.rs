@@ -1,12 +1,15 @@
 mod alternative;
+mod calendar;
 mod copy;
 mod delete;
 mod edit;
 mod join;
+mod joined;
 mod kick;
 mod leave;
+mod list;
 mod settings;
-mod tags;
+pub(crate) mod tags;
 
 pub use edit::{EditManager, EditRow};
 pub use kick::KickComponent;
@@ -3,13 +3,15 @@ use serenity::all::{
 };
 use sqlx::{Database, Pool};
 
+use crate::locale::{LocaleManager, guild_locale};
 use crate::templates::{DefaultTemplate, Template};
-use crate::{Error, PostManager, Result};
+use crate::utils::owner_or_moderator;
+use crate::{PostManager, Result};
 
 use super::Components;
 
 impl Components {
-    pub async fn settings<Db: Database, Manager: PostManager<Db>>(
+    pub async fn settings<Db: Database, Manager: PostManager<Db> + LocaleManager<Db>>(
         ctx: &Context,
         interaction: &ComponentInteraction,
         pool: &Pool<Db>,
@@ -17,15 +19,21 @@ impl Components {
         let owner = match Manager::owner(pool, interaction.channel_id).await {
             Ok(owner) => owner,
             Err(sqlx::Error::RowNotFound) => interaction.user.id,
-            Err(e) => panic!("{e:?}"),
+            Err(e) => return Err(e.into()),
         };
 
-        if interaction.user.id != owner {
-            return Err(Error::PermissionDenied(owner));
-        }
+        owner_or_moderator(owner, interaction.user.id, interaction.member.as_ref())?;
+
+        // The buttons replace the post's own, which everyone sees
+        let locale = guild_locale::<Db, Manager>(
+            pool,
+            interaction.guild_id,
+            interaction.guild_locale.as_deref(),
+        )
+        .await?;
 
         let main_row = DefaultTemplate::main_row();
-        let settings_row = DefaultTemplate::settings_row();
+        let settings_row = DefaultTemplate::settings_row(&locale);
 
         interaction
             .create_response(
@@ -35,8 +43,7 @@ impl Components {
                         .components(vec![main_row, settings_row]),
                 ),
             )
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
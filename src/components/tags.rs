@@ -1,83 +1,257 @@
-use std::collections::HashSet;
-
 use serenity::all::{
-    ComponentInteraction, ComponentInteractionDataKind, Context, CreateInteractionResponse,
-    EditThread, ForumTagId,
+    ButtonStyle, ComponentInteraction, ComponentInteractionDataKind, Context, CreateActionRow,
+    CreateButton, CreateInteractionResponse, CreateInteractionResponseMessage, CreateSelectMenu,
+    CreateSelectMenuKind, CreateSelectMenuOption, EditThread, ForumEmoji, ForumTag, ForumTagId,
+    GuildChannel, ReactionType,
 };
+use sqlx::{Database, Pool};
+
+use crate::locale::{LocaleManager, message, message_with};
+use crate::models::{AuditAction, AuditEntry, AuditManager, ModLog, ModLogManager};
+use crate::{Error, PostManager, Result};
 
-use crate::Result;
+/// Discord allows at most 25 options in a select menu
+const PAGE_SIZE: usize = 25;
+/// Discord allows at most 5 tags on a forum post
+const MAX_TAGS: usize = 5;
 
 pub struct TagsComponent;
 
 impl TagsComponent {
-    pub async fn add(ctx: &Context, interaction: &ComponentInteraction) -> Result<()> {
-        let mut tag_ids = match &interaction.data.kind {
-            ComponentInteractionDataKind::StringSelect { values } => values
-                .iter()
-                .map(|x| x.parse::<u64>().unwrap())
-                .map(|id| ForumTagId::new(id))
-                .collect::<HashSet<_>>(),
-            _ => unreachable!("Expected string select"),
-        };
-
-        let mut channel = interaction
+    /// Handles the `lfg_tags_select_{page}` menu and `lfg_tags_page_{page}` buttons of the tag
+    /// editor.
+    pub async fn run<
+        Db: Database,
+        Manager: PostManager<Db> + AuditManager<Db> + ModLogManager<Db> + LocaleManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let mut thread = interaction
             .channel_id
             .to_channel(ctx)
-            .await
-            .unwrap()
+            .await?
             .guild()
-            .unwrap();
-
-        tag_ids.extend(channel.applied_tags.iter().copied());
+            .ok_or(Error::InvalidChannel)?;
 
-        channel
-            .edit_thread(ctx, EditThread::new().applied_tags(tag_ids))
-            .await
-            .unwrap();
+        let forum = forum(ctx, &thread).await?;
 
-        interaction
-            .create_response(ctx, CreateInteractionResponse::Acknowledge)
-            .await
-            .unwrap();
+        let (action, page) = interaction
+            .data
+            .custom_id
+            .trim_start_matches("lfg_tags_")
+            .split_once('_')
+            .unwrap_or(("page", "0"));
+        let page = page.parse::<usize>().unwrap_or_default();
 
-        Ok(())
-    }
+        if action == "select" {
+            let ComponentInteractionDataKind::StringSelect { values } = &interaction.data.kind
+            else {
+                unreachable!("Expected string select");
+            };
 
-    pub async fn remove(ctx: &Context, interaction: &ComponentInteraction) -> Result<()> {
-        let selected_ids = match &interaction.data.kind {
-            ComponentInteractionDataKind::StringSelect { values } => values
+            let selected = values
                 .iter()
-                .map(|x| x.parse::<u64>().unwrap())
+                .filter_map(|value| value.parse::<u64>().ok())
                 .map(ForumTagId::new)
-                .collect::<HashSet<_>>(),
-            _ => unreachable!("Expected string select"),
-        };
+                .collect::<Vec<_>>();
 
-        let mut channel = interaction
-            .channel_id
-            .to_channel(ctx)
-            .await
-            .unwrap()
-            .guild()
-            .unwrap();
+            let on_page = page_tags(&forum.available_tags, page)
+                .iter()
+                .map(|tag| tag.id)
+                .collect::<Vec<_>>();
 
-        let new_tag_ids = channel
-            .applied_tags
-            .iter()
-            .copied()
-            .filter(|tag_id| !selected_ids.contains(tag_id))
-            .collect::<HashSet<_>>();
+            // Tags on other pages stay applied, the ones on this page are toggled by the menu
+            let mut applied = thread
+                .applied_tags
+                .iter()
+                .copied()
+                .filter(|id| !on_page.contains(id))
+                .collect::<Vec<_>>();
+            applied.extend(selected);
+
+            if applied.len() > MAX_TAGS {
+                return Err(Error::TagLimit);
+            }
+
+            let before = tag_names(&forum.available_tags, &thread.applied_tags);
+            let after = tag_names(&forum.available_tags, &applied);
+
+            thread
+                .edit_thread(ctx, EditThread::new().applied_tags(applied))
+                .await?;
+
+            if before != after {
+                let entry = AuditEntry::new(thread.id, AuditAction::Tags)
+                    .guild(interaction.guild_id)
+                    .actor(Some(interaction.user.id))
+                    .before(before)
+                    .after(after);
+                Manager::record(pool, entry).await?;
+
+                // Only the owner and moderators can open the editor
+                let owner = Manager::owner(pool, thread.id).await?;
+                if owner != interaction.user.id {
+                    ModLog::Override {
+                        thread: thread.id,
+                        owner,
+                        actor: interaction.user.id,
+                        action: AuditAction::Tags,
+                    }
+                    .send::<Db, Manager>(ctx, pool, interaction.guild_id)
+                    .await;
+                }
+            }
+        }
 
-        channel
-            .edit_thread(ctx, EditThread::new().applied_tags(new_tag_ids))
-            .await
-            .unwrap();
+        let (content, components) = tag_editor(
+            &forum.available_tags,
+            &thread.applied_tags,
+            page,
+            &interaction.locale,
+        )?;
 
         interaction
-            .create_response(ctx, CreateInteractionResponse::Acknowledge)
-            .await
-            .unwrap();
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(components),
+                ),
+            )
+            .await?;
 
         Ok(())
     }
 }
+
+pub(crate) async fn forum(ctx: &Context, thread: &GuildChannel) -> Result<GuildChannel> {
+    thread
+        .parent_id
+        .ok_or(Error::InvalidChannel)?
+        .to_channel(ctx)
+        .await?
+        .guild()
+        .ok_or(Error::InvalidChannel)
+}
+
+/// The names of the applied tags, in the forum's order, as recorded in the audit log.
+fn tag_names(available: &[ForumTag], applied: &[ForumTagId]) -> String {
+    let names = available
+        .iter()
+        .filter(|tag| applied.contains(&tag.id))
+        .map(|tag| tag.name.as_str())
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        return String::from("None");
+    }
+
+    names.join(", ")
+}
+
+/// The message content and components of the tag editor showing `page` of the forum's tags.
+pub(crate) fn tag_editor(
+    available: &[ForumTag],
+    applied: &[ForumTagId],
+    page: usize,
+    locale: &str,
+) -> Result<(String, Vec<CreateActionRow>)> {
+    if available.is_empty() {
+        return Err(Error::NoTags);
+    }
+
+    let pages = available.len().div_ceil(PAGE_SIZE);
+    let page = page.min(pages - 1);
+
+    let applied_names = available
+        .iter()
+        .filter(|tag| applied.contains(&tag.id))
+        .map(|tag| tag.name.as_str())
+        .collect::<Vec<_>>();
+
+    let tags = if applied_names.is_empty() {
+        message(locale, "tags.none")
+    } else {
+        applied_names.join(", ")
+    };
+
+    let mut content = message_with(
+        locale,
+        "tags.applied",
+        &[
+            ("count", &applied_names.len()),
+            ("max", &MAX_TAGS),
+            ("tags", &tags),
+        ],
+    );
+
+    if pages > 1 {
+        content.push('\n');
+        content.push_str(&message_with(
+            locale,
+            "tags.page",
+            &[("page", &(page + 1)), ("pages", &pages)],
+        ));
+    }
+
+    let options = page_tags(available, page)
+        .iter()
+        .map(|tag| {
+            let mut option = CreateSelectMenuOption::new(&tag.name, tag.id.to_string())
+                .default_selection(applied.contains(&tag.id));
+
+            match &tag.emoji {
+                Some(ForumEmoji::Name(name)) => {
+                    option = option.emoji(ReactionType::Unicode(name.clone()))
+                }
+                Some(ForumEmoji::Id(id)) => {
+                    option = option.emoji(ReactionType::Custom {
+                        animated: false,
+                        id: *id,
+                        name: None,
+                    })
+                }
+                _ => {}
+            }
+
+            option
+        })
+        .collect::<Vec<_>>();
+
+    let max_values = options.len().min(MAX_TAGS) as u8;
+
+    let mut components = vec![CreateActionRow::SelectMenu(
+        CreateSelectMenu::new(
+            format!("lfg_tags_select_{page}"),
+            CreateSelectMenuKind::String { options },
+        )
+        .placeholder(message(locale, "tags.placeholder"))
+        .min_values(0)
+        .max_values(max_values),
+    )];
+
+    if pages > 1 {
+        components.push(CreateActionRow::Buttons(vec![
+            CreateButton::new(format!("lfg_tags_page_{}", page.saturating_sub(1)))
+                .label(message(locale, "list.previous"))
+                .style(ButtonStyle::Secondary)
+                .disabled(page == 0),
+            CreateButton::new(format!("lfg_tags_page_{}", page + 1))
+                .label(message(locale, "list.next"))
+                .style(ButtonStyle::Secondary)
+                .disabled(page + 1 == pages),
+        ]));
+    }
+
+    Ok((content, components))
+}
+
+fn page_tags(available: &[ForumTag], page: usize) -> &[ForumTag] {
+    let start = (page * PAGE_SIZE).min(available.len());
+    let end = (start + PAGE_SIZE).min(available.len());
+
+    &available[start..end]
+}
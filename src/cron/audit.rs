@@ -0,0 +1,18 @@
+use serenity::all::Context;
+use sqlx::Database;
+use zayden_core::{CronJob, cron::CronJobs};
+
+use crate::models::AuditManager;
+use crate::models::audit::retention_cutoff;
+
+/// Schedules a daily job removing audit entries past the retention period.
+pub async fn prune_audit_log<Db: Database, Manager: AuditManager<Db>>(ctx: &Context) {
+    let job = CronJob::<Db>::new("0 0 4 * * * *").set_action(|_ctx, pool| async move {
+        if let Err(e) = Manager::prune(&pool, retention_cutoff()).await {
+            eprintln!("Failed to prune the audit log: {e:?}");
+        }
+    });
+
+    let mut data = ctx.data.write().await;
+    data.entry::<CronJobs<Db>>().or_insert(Vec::new()).push(job);
+}
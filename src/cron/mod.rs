@@ -1,3 +1,7 @@
+pub mod audit;
 pub mod reminders;
+pub mod status_tags;
 
+pub use audit::prune_audit_log;
 pub use reminders::create_reminders;
+pub use status_tags::schedule_status_tags;
@@ -4,17 +4,24 @@ use serenity::all::{ChannelId, Colour, Context, CreateEmbed, CreateMessage, Ment
 use sqlx::{Database, Pool};
 use zayden_core::{CronJob, cron::CronJobs};
 
-use crate::{Join, PostManager, PostRow};
+use crate::locale::{LocaleManager, guild_locale, message, message_with};
+use crate::utils::update_embeds;
+use crate::{Join, PostManager, PostRow, Result, TemplateManager};
 
-pub async fn create_reminders<Db: Database, Manager: PostManager<Db>>(
+/// How many minutes before a post starts its reminders are sent.
+pub const REMINDER_OFFSETS_MINS: [i64; 3] = [7 * 24 * 60, 24 * 60, 30];
+
+pub async fn create_reminders<
+    Db: Database,
+    Manager: PostManager<Db> + TemplateManager<Db> + LocaleManager<Db>,
+>(
     ctx: &Context,
     row: &PostRow,
 ) {
     let post_id = row.channel();
 
-    let week = row.start_time - Duration::days(7);
-    let day = row.start_time - Duration::hours(24);
-    let mins_30 = row.start_time - Duration::minutes(30);
+    let [week, day, mins_30] =
+        REMINDER_OFFSETS_MINS.map(|offset| row.start_time - Duration::minutes(offset));
 
     let week_job = CronJob::<Db>::new(&format!(
         "0 {} {} {} {} * {}",
@@ -25,7 +32,9 @@ pub async fn create_reminders<Db: Database, Manager: PostManager<Db>>(
         week.year()
     ))
     .set_action(move |ctx, pool| async move {
-        reminder::<Db, Manager>(ctx, pool, post_id).await;
+        if let Err(e) = reminder::<Db, Manager>(ctx, pool, post_id).await {
+            eprintln!("Failed to send the reminder for '{post_id}': {e:?}");
+        }
     });
 
     let day_job = CronJob::<Db>::new(&format!(
@@ -37,7 +46,9 @@ pub async fn create_reminders<Db: Database, Manager: PostManager<Db>>(
         day.year()
     ))
     .set_action(move |ctx, pool| async move {
-        reminder::<Db, Manager>(ctx, pool, post_id).await;
+        if let Err(e) = reminder::<Db, Manager>(ctx, pool, post_id).await {
+            eprintln!("Failed to send the reminder for '{post_id}': {e:?}");
+        }
     });
 
     let mins_30_job = CronJob::<Db>::new(&format!(
@@ -49,40 +60,101 @@ pub async fn create_reminders<Db: Database, Manager: PostManager<Db>>(
         mins_30.year()
     ))
     .set_action(move |ctx, pool| async move {
-        reminder::<Db, Manager>(ctx, pool, post_id).await;
+        if let Err(e) = reminder::<Db, Manager>(ctx, pool, post_id).await {
+            eprintln!("Failed to send the reminder for '{post_id}': {e:?}");
+        }
+    });
+
+    let start = row.start_time;
+
+    let start_job = CronJob::<Db>::new(&format!(
+        "0 {} {} {} {} * {}",
+        start.minute(),
+        start.hour(),
+        start.day(),
+        start.month(),
+        start.year()
+    ))
+    .set_action(move |ctx, pool| async move {
+        if let Err(e) = started::<Db, Manager>(ctx, pool, post_id).await {
+            eprintln!("Failed to update the post '{post_id}': {e:?}");
+        }
     });
 
     let mut data = ctx.data.write().await;
     let jobs = data.entry::<CronJobs<Db>>().or_insert(Vec::new());
 
-    jobs.extend([week_job, day_job, mins_30_job]);
+    jobs.extend([week_job, day_job, mins_30_job, start_job]);
 }
 
-async fn reminder<Db: Database, Manager: PostManager<Db>>(
+/// Re-renders the embeds so the post shows as started.
+async fn started<
+    Db: Database,
+    Manager: PostManager<Db> + TemplateManager<Db> + LocaleManager<Db>,
+>(
     ctx: Context,
     pool: Pool<Db>,
     id: ChannelId,
-) {
+) -> Result<()> {
+    let post = match Manager::row(&pool, id).await {
+        Ok(post) => post,
+        Err(sqlx::Error::RowNotFound) => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let owner = post.owner().to_user(&ctx).await?;
+
+    let locale = post_locale::<Db, Manager>(&ctx, &pool, id).await?;
+
+    update_embeds::<Db, Manager>(&ctx, &pool, &post, owner.display_name(), id, &locale).await
+}
+
+/// The language of the guild the post belongs to.
+async fn post_locale<Db: Database, Manager: LocaleManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    id: ChannelId,
+) -> sqlx::Result<String> {
+    let guild_id = id
+        .to_channel(ctx)
+        .await
+        .ok()
+        .and_then(|channel| channel.guild())
+        .map(|channel| channel.guild_id);
+
+    guild_locale::<Db, Manager>(pool, guild_id, None).await
+}
+
+async fn reminder<Db: Database, Manager: PostManager<Db> + LocaleManager<Db>>(
+    ctx: Context,
+    pool: Pool<Db>,
+    id: ChannelId,
+) -> Result<()> {
     let post = match Manager::row(&pool, id).await {
         Ok(post) => post,
         Err(sqlx::Error::RowNotFound) => {
             println!("Post for '{}' not found", id);
-            return;
+            return Ok(());
         }
-        Err(e) => panic!("{e:?}"),
+        Err(e) => return Err(e.into()),
     };
 
     let timestamp = post.start_time.timestamp();
+    let locale = post_locale::<Db, Manager>(&ctx, &pool, id).await?;
 
     let embed = CreateEmbed::new()
         .title(format!("{} - <t:{timestamp}>", &post.activity))
         .colour(Colour::BLUE)
-        .description(format!(
-            "Starting <t:{timestamp}:R>\nThread: {}",
-            post.channel().mention()
+        .description(message_with(
+            &locale,
+            "reminder.starting",
+            &[
+                ("time", &format!("<t:{timestamp}:R>")),
+                ("thread", &post.channel().mention()),
+            ],
         ))
         .field(
-            "Joined",
+            message(&locale, "reminder.joined"),
             post.fireteam()
                 .map(|user| user.mention().to_string())
                 .collect::<Vec<_>>()
@@ -94,5 +166,8 @@ async fn reminder<Db: Database, Manager: PostManager<Db>>(
         .fireteam()
         .map(|user| user.dm(&ctx, CreateMessage::new().embed(embed.clone())));
 
+    // A closed DM only skips that guardian
     future::join_all(iter).await;
+
+    Ok(())
 }
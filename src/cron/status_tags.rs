@@ -0,0 +1,63 @@
+use chrono::{Datelike, Duration, Timelike, Utc};
+use serenity::all::{ChannelId, Context};
+use sqlx::{Database, Pool};
+use zayden_core::{CronJob, cron::CronJobs};
+
+use crate::utils::sync_status_tags;
+use crate::{PostManager, PostRow, Result, TagMappingManager};
+
+/// How long before a post starts its "This week" and "Today" tags apply. The tags are also
+/// resynced at the start, when "Today" comes off.
+const STATUS_OFFSETS_DAYS: [i64; 3] = [7, 1, 0];
+
+/// Schedules resyncs of the post's status tags for when its time based tags change.
+pub async fn schedule_status_tags<
+    Db: Database,
+    Manager: PostManager<Db> + TagMappingManager<Db>,
+>(
+    ctx: &Context,
+    row: &PostRow,
+) {
+    let post_id = row.channel();
+    let now = Utc::now();
+
+    let jobs = STATUS_OFFSETS_DAYS
+        .map(|offset| row.start_time - Duration::days(offset))
+        .into_iter()
+        .filter(|at| *at > now)
+        .map(|at| {
+            CronJob::<Db>::new(&format!(
+                "0 {} {} {} {} * {}",
+                at.minute(),
+                at.hour(),
+                at.day(),
+                at.month(),
+                at.year()
+            ))
+            .set_action(move |ctx, pool| async move {
+                if let Err(e) = resync::<Db, Manager>(ctx, pool, post_id).await {
+                    eprintln!("Failed to sync the status tags of '{post_id}': {e:?}");
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut data = ctx.data.write().await;
+    let cron_jobs = data.entry::<CronJobs<Db>>().or_insert(Vec::new());
+
+    cron_jobs.extend(jobs);
+}
+
+async fn resync<Db: Database, Manager: PostManager<Db> + TagMappingManager<Db>>(
+    ctx: Context,
+    pool: Pool<Db>,
+    id: ChannelId,
+) -> Result<()> {
+    let post = match Manager::row(&pool, id).await {
+        Ok(post) => post,
+        Err(sqlx::Error::RowNotFound) => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    sync_status_tags::<Db, Manager>(&ctx, &pool, &post, id).await
+}
@@ -1,5 +1,13 @@
-use serenity::all::{Mentionable, UserId};
-use zayden_core::Error as ZaydenError;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use serenity::all::{
+    CommandInteraction, ComponentInteraction, Context, CreateInteractionResponse,
+    CreateInteractionResponseFollowup, CreateInteractionResponseMessage, DiscordJsonError,
+    ErrorResponse, HttpError, Mentionable, ModalInteraction, UserId,
+};
+
+use crate::locale::{DEFAULT_LOCALE, message, message_with};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -10,56 +18,214 @@ pub enum Error {
     FireteamFull,
     PermissionDenied(UserId),
     InvalidDateTime(String),
+    InvalidDifficulty(String),
+    InvalidFireteamSize { min: i16, max: i16 },
     TagRequired,
     AlreadyJoined,
+    NotInFireteam,
     InvalidChannel,
+    InvalidTag(String),
+    TagLimit,
+    NoTags,
+    InvalidGame(String),
+    MissingPermissions,
+    InvalidCatalog { path: PathBuf, reason: String },
+    InvalidTemplate(String),
+    InvalidLocale(String),
+    InvalidNumber(String),
+    InvalidTimezone(String),
+    MissingCategory,
+    PostNotFound,
+    MessageNotFound,
+    ChannelNotFound,
+    UserNotFound,
+    BotMissingPermissions,
 
     Serenity(serenity::Error),
     Sqlx(sqlx::Error),
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl Error {
+    /// The error message in the locale.
+    pub fn message(&self, locale: &str) -> String {
         match self {
-            Self::MissingGuildId => ZaydenError::MissingGuildId.fmt(f),
-            Self::MissingSetup => {
-                write!(
-                    f,
-                    "Missing setup. If you are the owner, please run `/lfg setup` to set up the bot."
-                )
-            }
-            Self::FireteamFull => write!(f, "Unable to join. Fireteam is full."),
-            Self::PermissionDenied(id) => write!(
-                f,
-                "Permission denied. Only the owner ({}) can use this action.",
-                id.mention()
+            Self::MissingGuildId => message(locale, "error.missing_guild_id"),
+            Self::MissingSetup => message(locale, "error.missing_setup"),
+            Self::FireteamFull => message(locale, "error.fireteam_full"),
+            Self::PermissionDenied(id) => message_with(
+                locale,
+                "error.permission_denied",
+                &[("owner", &id.mention())],
             ),
             Self::InvalidDateTime(format) => {
-                write!(f, "Invalid date time. Expected format: {}", format)
+                message_with(locale, "error.invalid_date_time", &[("format", format)])
+            }
+            Self::InvalidDifficulty(expected) => message_with(
+                locale,
+                "error.invalid_difficulty",
+                &[("expected", expected)],
+            ),
+            Self::InvalidFireteamSize { min, max } => message_with(
+                locale,
+                "error.invalid_fireteam_size",
+                &[("min", min), ("max", max)],
+            ),
+            Self::TagRequired => message(locale, "error.tag_required"),
+            Self::AlreadyJoined => message(locale, "error.already_joined"),
+            Self::NotInFireteam => message(locale, "error.not_in_fireteam"),
+            Self::InvalidChannel => message(locale, "error.invalid_channel"),
+            Self::InvalidTag(tag) => message_with(locale, "error.invalid_tag", &[("tag", tag)]),
+            Self::TagLimit => message(locale, "error.tag_limit"),
+            Self::NoTags => message(locale, "error.no_tags"),
+            Self::InvalidGame(game) => {
+                message_with(locale, "error.invalid_game", &[("game", game)])
+            }
+            Self::MissingPermissions => message(locale, "error.missing_permissions"),
+            Self::InvalidCatalog { path, reason } => message_with(
+                locale,
+                "error.invalid_catalog",
+                &[("path", &path.display()), ("reason", reason)],
+            ),
+            Self::InvalidTemplate(reason) => {
+                message_with(locale, "error.invalid_template", &[("reason", reason)])
             }
-            Self::TagRequired => {
-                write!(
-                    f,
-                    "Unable to parse Activity and apply necessary tags. Please fix the Activity field and use the edit button to update after creating the post."
-                )
+            Self::InvalidLocale(value) => {
+                message_with(locale, "error.invalid_locale", &[("locale", value)])
+            }
+            Self::InvalidNumber(value) => {
+                message_with(locale, "error.invalid_number", &[("value", value)])
+            }
+            Self::InvalidTimezone(timezone) => {
+                message_with(locale, "error.invalid_timezone", &[("timezone", timezone)])
+            }
+            Self::MissingCategory => message(locale, "error.missing_category"),
+            Self::PostNotFound => message(locale, "error.post_not_found"),
+            Self::MessageNotFound => message(locale, "error.message_not_found"),
+            Self::ChannelNotFound => message(locale, "error.channel_not_found"),
+            Self::UserNotFound => message(locale, "error.user_not_found"),
+            Self::BotMissingPermissions => message(locale, "error.bot_missing_permissions"),
+            Self::Serenity(_) => message(locale, "error.discord"),
+            Self::Sqlx(_) => message(locale, "error.database"),
+        }
+    }
+
+    /// Replies to the interaction with the error message, only visible to the user.
+    ///
+    /// Follows up instead when the interaction was already responded to or deferred.
+    pub async fn reply(
+        &self,
+        ctx: &Context,
+        interaction: &impl ErrorReply,
+    ) -> std::result::Result<(), serenity::Error> {
+        let content = self.message(interaction.locale());
+
+        let response = CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .content(&content)
+                .ephemeral(true),
+        );
+
+        match interaction.respond(ctx, response).await {
+            Ok(()) => Ok(()),
+            // Interaction has already been acknowledged
+            Err(serenity::Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse {
+                error: DiscordJsonError { code: 40060, .. },
+                ..
+            }))) => {
+                let followup = CreateInteractionResponseFollowup::new()
+                    .content(content)
+                    .ephemeral(true);
+
+                interaction.followup(ctx, followup).await
             }
-            Self::AlreadyJoined => write!(f, "You have already joined this LFG."),
-            Self::InvalidChannel => write!(f, "Invalid LFG channel."),
-            _ => write!(f, "Unhandled error"),
+            Err(e) => Err(e),
         }
     }
 }
 
+/// An interaction an [`Error`] can be reported to.
+#[async_trait]
+pub trait ErrorReply: Sync {
+    fn locale(&self) -> &str;
+
+    async fn respond(
+        &self,
+        ctx: &Context,
+        response: CreateInteractionResponse,
+    ) -> serenity::Result<()>;
+
+    async fn followup(
+        &self,
+        ctx: &Context,
+        followup: CreateInteractionResponseFollowup,
+    ) -> serenity::Result<()>;
+}
+
+macro_rules! impl_error_reply {
+    ($($interaction:ty),*) => {
+        $(
+            #[async_trait]
+            impl ErrorReply for $interaction {
+                fn locale(&self) -> &str {
+                    &self.locale
+                }
+
+                async fn respond(
+                    &self,
+                    ctx: &Context,
+                    response: CreateInteractionResponse,
+                ) -> serenity::Result<()> {
+                    self.create_response(ctx, response).await
+                }
+
+                async fn followup(
+                    &self,
+                    ctx: &Context,
+                    followup: CreateInteractionResponseFollowup,
+                ) -> serenity::Result<()> {
+                    self.create_followup(ctx, followup).await.map(|_| ())
+                }
+            }
+        )*
+    };
+}
+
+impl_error_reply!(CommandInteraction, ComponentInteraction, ModalInteraction);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message(DEFAULT_LOCALE))
+    }
+}
+
 impl std::error::Error for Error {}
 
 impl From<serenity::Error> for Error {
     fn from(value: serenity::Error) -> Self {
-        Self::Serenity(value)
+        let code = match &value {
+            serenity::Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse {
+                error: DiscordJsonError { code, .. },
+                ..
+            })) => *code,
+            _ => return Self::Serenity(value),
+        };
+
+        match code {
+            10003 => Self::ChannelNotFound,
+            10008 => Self::MessageNotFound,
+            10013 => Self::UserNotFound,
+            50013 => Self::BotMissingPermissions,
+            _ => Self::Serenity(value),
+        }
     }
 }
 
 impl From<sqlx::Error> for Error {
     fn from(value: sqlx::Error) -> Self {
-        Self::Sqlx(value)
+        match value {
+            // Posts are the only rows fetched without an Option
+            sqlx::Error::RowNotFound => Self::PostNotFound,
+            _ => Self::Sqlx(value),
+        }
     }
 }
@@ -1,42 +1,63 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serenity::all::{
-    Context, DiscordJsonError, EditThread, ErrorResponse, Guild, HttpError, PartialGuildChannel,
+    Context, DiscordJsonError, EditThread, ErrorResponse, Guild, GuildChannel, HttpError,
+    PartialGuildChannel,
 };
 use sqlx::{Database, Pool};
 
-use crate::{GuildManager, PostManager, actions, cron::create_reminders, templates::TemplateInfo};
+use crate::{
+    ActivityCatalog, AuditManager, CustomActivityManager, GuildManager, LocaleManager,
+    ModLogManager, PostManager, Result, ScheduleManager, TagMappingManager, TemplateManager,
+    actions,
+    cron::{create_reminders, schedule_status_tags},
+    templates::TemplateInfo,
+    utils::{sync_schedule, sync_status_tags},
+};
 
-pub async fn thread_delete<Db: Database, Manager: PostManager<Db>>(
+pub async fn thread_delete<
+    Db: Database,
+    Manager: PostManager<Db>
+        + AuditManager<Db>
+        + ModLogManager<Db>
+        + ScheduleManager<Db>
+        + LocaleManager<Db>,
+>(
     ctx: &Context,
     thread: &PartialGuildChannel,
     pool: &Pool<Db>,
-) {
-    if Manager::exists(pool, thread.id).await.unwrap() {
-        actions::delete::<Db, Manager>(ctx, thread.id, pool)
-            .await
-            .unwrap();
+) -> Result<()> {
+    if Manager::exists(pool, thread.id).await? {
+        actions::delete::<Db, Manager>(ctx, thread.id, pool, Some(thread.guild_id), None).await?;
     }
+
+    Ok(())
 }
 
 pub async fn guild_create<
     Db: Database,
-    GuildHandler: GuildManager<Db>,
-    PostHandler: PostManager<Db>,
+    GuildHandler: GuildManager<Db> + CustomActivityManager<Db>,
+    PostHandler: PostManager<Db>
+        + TemplateManager<Db>
+        + TagMappingManager<Db>
+        + LocaleManager<Db>
+        + ScheduleManager<Db>,
 >(
     ctx: &Context,
     guild: &Guild,
     pool: &Pool<Db>,
-) {
-    let Ok(Some(guild_row)) = GuildHandler::row(pool, guild.id).await else {
-        return;
+) -> Result<()> {
+    let Some(guild_row) = GuildHandler::row(pool, guild.id).await? else {
+        return Ok(());
     };
 
+    // Posts of custom activities render with them from the start
+    ActivityCatalog::load_custom::<Db, GuildHandler>(pool, guild.id).await?;
+
     let lfg_channel = guild_row.channel_id();
 
     let archived_threads = lfg_channel
         .get_archived_public_threads(&ctx, None, Some(100))
-        .await
-        .unwrap();
+        .await?;
 
     let threads = guild
         .threads
@@ -46,71 +67,116 @@ pub async fn guild_create<
         .cloned();
 
     let now = Utc::now();
+
+    for thread in threads {
+        let id = thread.id;
+
+        // One thread failing shouldn't stop the rest from catching up
+        if let Err(e) = resume_thread::<Db, PostHandler>(ctx, pool, thread, now).await {
+            eprintln!("Failed to resume '{id}': {e:?}");
+        }
+    }
+
+    // Also takes off the posts that started while the bot was offline
+    sync_schedule::<Db, PostHandler>(ctx, Some(guild.id)).await;
+
+    Ok(())
+}
+
+/// Archives, deletes and catches up a thread of the LFG channel after the bot was offline.
+async fn resume_thread<
+    Db: Database,
+    PostHandler: PostManager<Db>
+        + TemplateManager<Db>
+        + TagMappingManager<Db>
+        + LocaleManager<Db>
+        + ScheduleManager<Db>,
+>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    mut thread: GuildChannel,
+    now: DateTime<Utc>,
+) -> Result<()> {
     let week_ago = now - Duration::days(7);
     let month_ago = now - Duration::days(30);
 
-    for mut thread in threads {
-        let created_at = *thread.last_message_id.unwrap().created_at();
+    // Threads without messages count from their creation
+    let created_at = *thread
+        .last_message_id
+        .map_or_else(|| thread.id.created_at(), |id| id.created_at());
+
+    if created_at < month_ago {
+        thread.delete(ctx).await?;
+    }
 
-        if created_at < month_ago {
-            thread.delete(ctx).await.unwrap();
+    if created_at < week_ago {
+        match thread
+            .edit_thread(ctx, EditThread::new().archived(true))
+            .await
+        {
+            Ok(_)
+            // Unknown Channel
+            | Err(serenity::Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse {
+                error: DiscordJsonError { code: 10003, .. },
+                ..
+            }))) => {}
+            Err(e) => return Err(e.into()),
         }
+    }
+
+    let mut post = match PostHandler::row(pool, thread.id).await {
+        Ok(post) => post,
+        Err(sqlx::Error::RowNotFound) => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    // Backfills the posts stored before posts had a guild, so they show in the guild's lists
+    if post.guild_id.is_none() {
+        PostHandler::save_guild(pool, thread.id, thread.guild_id).await?;
+        post.guild_id = Some(thread.guild_id.get() as i64);
+    }
+
+    if post.start_time > now {
+        create_reminders::<Db, PostHandler>(ctx, &post).await;
+        schedule_status_tags::<Db, PostHandler>(ctx, &post).await;
+    }
+
+    // Catches up on the time based tags that changed while the bot was offline, leaving the
+    // threads that are archived below alone
+    if post.start_time + Duration::hours(2) >= now {
+        sync_status_tags::<Db, PostHandler>(ctx, pool, &post, post.channel()).await?;
+    }
 
-        if created_at < week_ago {
-            match thread
-                .edit_thread(ctx, EditThread::new().archived(true))
-                .await
-            {
+    if post.start_time < now {
+        if let (Some(channel), Some(message)) = (post.alt_channel(), post.alt_message()) {
+            match channel.delete_message(ctx, message).await {
                 Ok(_)
-                // Unknown Channel
+                // Unknown Message
                 | Err(serenity::Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse {
-                    error: DiscordJsonError { code: 10003, .. },
+                    error: DiscordJsonError { code: 10008, .. },
                     ..
                 }))) => {}
-                Err(e) => panic!("{e:?}"),
-            }
+                Err(e) => return Err(e.into()),
+            };
         }
+    }
 
-        let post = match PostHandler::row(pool, thread.id).await {
-            Ok(post) => post,
-            Err(_) => continue,
-        };
+    if post.start_time + Duration::hours(2) < now {
+        post.channel()
+            .edit_thread(ctx, EditThread::new().archived(true))
+            .await?;
+    }
 
-        if post.start_time > now {
-            create_reminders::<Db, PostHandler>(ctx, &post).await;
-        }
+    /*
+        for post in posts {
+            let thread = post.channel();
 
-        if post.start_time < now {
-            if let (Some(channel), Some(message)) = (post.alt_channel(), post.alt_message()) {
-                match channel.delete_message(ctx, message).await {
-                    Ok(_)
-                    // Unknown Message
-                    | Err(serenity::Error::Http(HttpError::UnsuccessfulRequest(ErrorResponse {
-                        error: DiscordJsonError { code: 10008, .. },
-                        ..
-                    }))) => {}
-                    Err(e) => panic!("{e:?}"),
-                };
+            if !threads.contain(thread) {
+                actions::delete::<Db, PostHandler>(ctx, thread, pool)
+                        .await?;
             }
         }
+    */
 
-        if post.start_time + Duration::hours(2) < now {
-            post.channel()
-                .edit_thread(ctx, EditThread::new().archived(true))
-                .await
-                .unwrap();
-        }
-
-        /*
-            for post in posts {
-                let thread = post.channel();
-
-                if !threads.contain(thread) {
-                    actions::delete::<Db, PostHandler>(ctx, thread, pool)
-                            .await
-                            .unwrap();
-                }
-            }
-        */
-    }
+    Ok(())
 }
@@ -1,19 +1,28 @@
 pub mod actions;
 pub mod activities;
+pub mod calendar;
 pub mod commands;
 pub mod components;
 pub mod cron;
 pub mod error;
 pub mod events;
+pub mod locale;
 pub mod modals;
 pub mod models;
 pub mod templates;
 pub mod utils;
 
-pub use activities::{ACTIVITIES, Activity, ActivityCategory};
-pub use commands::{Command, JoinedManager, JoinedRow};
+pub use activities::{ACTIVITIES, Activity, ActivityCatalog, ActivityCategory, Difficulty, Game};
+pub use commands::{Command, CustomActivityManager, CustomActivityRow, JoinedManager, JoinedRow};
 pub use components::{Components, KickComponent, TagsComponent};
-pub use error::Error;
 use error::Result;
-pub use modals::{Create, Edit, GuildManager};
-pub use models::{Join, Leave, PostBuilder, PostManager, PostRow, Savable, TimezoneManager};
+pub use error::{Error, ErrorReply};
+pub use locale::LocaleManager;
+pub use modals::{Create, Edit, EditTemplate, GuildManager};
+pub use models::{
+    AnnouncementManager, AnnouncementMode, AuditAction, AuditEntry, AuditManager, AuditRow,
+    CalendarTokenManager, CalendarTokenRow, GameManager, Join, Leave, ModLog, ModLogManager,
+    PostBuilder, PostManager, PostRow, Savable, ScheduleManager, StatusTag, StatusTagRow,
+    TagMappingManager, TagMappingRow, ThreadTitleManager, ThreadTitleRow, TimezoneManager,
+};
+pub use templates::{GuildTemplateRow, TemplateKind, TemplateManager, TextTemplate};
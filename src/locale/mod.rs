@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+use async_trait::async_trait;
+use serenity::all::GuildId;
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::templates::text::render;
+
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// The message catalogs, keyed by Discord locale.
+///
+/// Messages are looked up in the locale, then in another locale of the same language, then in
+/// English. `{name}` placeholders are filled in by [`message_with`].
+const CATALOGS: [(&str, &str); 32] = [
+    ("en-US", include_str!("../../locales/en-US.toml")),
+    ("en-GB", include_str!("../../locales/en-GB.toml")),
+    ("bg", include_str!("../../locales/bg.toml")),
+    ("cs", include_str!("../../locales/cs.toml")),
+    ("da", include_str!("../../locales/da.toml")),
+    ("de", include_str!("../../locales/de.toml")),
+    ("el", include_str!("../../locales/el.toml")),
+    ("es-ES", include_str!("../../locales/es-ES.toml")),
+    ("es-419", include_str!("../../locales/es-419.toml")),
+    ("fi", include_str!("../../locales/fi.toml")),
+    ("fr", include_str!("../../locales/fr.toml")),
+    ("hi", include_str!("../../locales/hi.toml")),
+    ("hr", include_str!("../../locales/hr.toml")),
+    ("hu", include_str!("../../locales/hu.toml")),
+    ("id", include_str!("../../locales/id.toml")),
+    ("it", include_str!("../../locales/it.toml")),
+    ("ja", include_str!("../../locales/ja.toml")),
+    ("ko", include_str!("../../locales/ko.toml")),
+    ("lt", include_str!("../../locales/lt.toml")),
+    ("nl", include_str!("../../locales/nl.toml")),
+    ("no", include_str!("../../locales/no.toml")),
+    ("pl", include_str!("../../locales/pl.toml")),
+    ("pt-BR", include_str!("../../locales/pt-BR.toml")),
+    ("ro", include_str!("../../locales/ro.toml")),
+    ("ru", include_str!("../../locales/ru.toml")),
+    ("sv-SE", include_str!("../../locales/sv-SE.toml")),
+    ("th", include_str!("../../locales/th.toml")),
+    ("tr", include_str!("../../locales/tr.toml")),
+    ("uk", include_str!("../../locales/uk.toml")),
+    ("vi", include_str!("../../locales/vi.toml")),
+    ("zh-CN", include_str!("../../locales/zh-CN.toml")),
+    ("zh-TW", include_str!("../../locales/zh-TW.toml")),
+];
+
+static MESSAGES: LazyLock<HashMap<&'static str, HashMap<String, String>>> = LazyLock::new(|| {
+    CATALOGS
+        .into_iter()
+        .map(|(locale, catalog)| {
+            let table = catalog
+                .parse::<toml::Table>()
+                .unwrap_or_else(|e| panic!("Invalid message catalog '{locale}': {e}"));
+
+            let mut messages = HashMap::new();
+            flatten(&mut messages, "", table);
+
+            (locale, messages)
+        })
+        .collect()
+});
+
+#[async_trait]
+pub trait LocaleManager<Db: Database> {
+    async fn guild_locale(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<String>>;
+
+    async fn save_guild_locale(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        locale: &str,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+/// The supported locales and the name of their language, in the language itself.
+pub fn locales() -> impl Iterator<Item = (&'static str, String)> {
+    CATALOGS
+        .into_iter()
+        .map(|(locale, _)| (locale, message(locale, "name")))
+}
+
+/// The supported locale matching `locale`, by code or language name.
+pub fn find_locale(locale: &str) -> Option<&'static str> {
+    let locale = locale.trim();
+
+    locales()
+        .find(|(code, name)| code.eq_ignore_ascii_case(locale) || name.eq_ignore_ascii_case(locale))
+        .map(|(code, _)| code)
+}
+
+/// The language of content shared in the guild's threads: the language set with
+/// `/lfg config language`, else `fallback`, usually the guild's community locale.
+pub async fn guild_locale<Db: Database, Manager: LocaleManager<Db>>(
+    pool: &Pool<Db>,
+    guild_id: Option<GuildId>,
+    fallback: Option<&str>,
+) -> sqlx::Result<String> {
+    let locale = match guild_id {
+        Some(guild_id) => Manager::guild_locale(pool, guild_id).await?,
+        None => None,
+    };
+
+    Ok(locale
+        .or_else(|| fallback.map(String::from))
+        .unwrap_or_else(|| DEFAULT_LOCALE.to_string()))
+}
+
+pub fn message(locale: &str, key: &str) -> String {
+    let language = locale.split('-').next().unwrap_or(locale);
+
+    let fallbacks = CATALOGS
+        .iter()
+        .map(|(code, _)| *code)
+        .filter(|code| *code != locale && code.split('-').next() == Some(language));
+
+    [locale]
+        .into_iter()
+        .chain(fallbacks)
+        .chain([DEFAULT_LOCALE])
+        .find_map(|locale| MESSAGES.get(locale)?.get(key))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
+
+pub fn message_with(locale: &str, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    render(&message(locale, key), |name| {
+        args.iter()
+            .find(|(arg, _)| *arg == name)
+            .map(|(_, value)| value.to_string())
+            .unwrap_or_default()
+    })
+}
+
+fn flatten(messages: &mut HashMap<String, String>, prefix: &str, table: toml::Table) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}.{key}")
+        };
+
+        match value {
+            toml::Value::String(message) => {
+                messages.insert(key, message);
+            }
+            toml::Value::Table(table) => flatten(messages, &key, table),
+            _ => {}
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::Utc;
 use serenity::all::{
     AutoArchiveDuration, ChannelId, Context, CreateForumPost, CreateInteractionResponse,
     CreateMessage, DiscordJsonError, ErrorResponse, GuildId, HttpError, Mentionable,
@@ -8,12 +9,21 @@ use sqlx::prelude::FromRow;
 use sqlx::{Database, Pool};
 use zayden_core::parse_modal_data;
 
-use crate::cron::create_reminders;
-use crate::templates::{DefaultTemplate, Template};
-use crate::{ACTIVITIES, Error, PostBuilder, PostManager, Result};
+use crate::activities::{DEFAULT_GAME, resolve};
+use crate::cron::{create_reminders, schedule_status_tags};
+use crate::locale::guild_locale;
+use crate::models::audit::snapshot;
+use crate::models::tag_mapping::{activity_tags, with_status_tags};
+use crate::models::{AuditAction, AuditEntry, AuditManager, ScheduleManager};
+use crate::templates::TemplateKind;
+use crate::utils::sync_schedule;
+use crate::{
+    ActivityCatalog, CustomActivityManager, Error, GameManager, LocaleManager, PostBuilder,
+    PostManager, Result, TagMappingManager, TemplateManager, ThreadTitleManager,
+};
 use crate::{PostRow, Savable, TimezoneManager};
 
-use super::start_time;
+use super::{parse_difficulty, start_time, validate_fireteam_size};
 
 #[async_trait]
 pub trait GuildManager<Db: Database> {
@@ -41,8 +51,20 @@ pub struct Create;
 impl Create {
     pub async fn run<
         Db: Database,
-        GuildHandler: GuildManager<Db>,
-        PostHandler: PostManager<Db> + Savable<Db, PostRow>,
+        GuildHandler: GuildManager<Db>
+            + GameManager<Db>
+            + CustomActivityManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>,
+        PostHandler: PostManager<Db>
+            + TemplateManager<Db>
+            + TagMappingManager<Db>
+            + LocaleManager<Db>
+            + AuditManager<Db>
+            + ScheduleManager<Db>
+            + Savable<Db, PostRow>,
         TzManager: TimezoneManager<Db>,
     >(
         ctx: &Context,
@@ -58,9 +80,11 @@ impl Create {
             .expect("Activity should exist as it's required");
         let fireteam_size = inputs
             .remove("fireteam size")
-            .expect("Fireteam size should exist as it's required")
+            .expect("Fireteam size should exist as it's required");
+        let fireteam_size = fireteam_size
+            .trim()
             .parse::<i16>()
-            .unwrap();
+            .map_err(|_| Error::InvalidNumber(fireteam_size.to_string()))?;
         let description = match inputs.remove("description") {
             Some(description) => &description.chars().take(1024).collect::<String>(),
             None => activity,
@@ -69,55 +93,117 @@ impl Create {
             .remove("start time")
             .expect("Start time should exist as it's required");
 
-        let timezone = TzManager::get(pool, interaction.user.id, &interaction.locale)
-            .await
-            .unwrap();
+        let timezone = TzManager::get(pool, interaction.user.id, &interaction.locale).await?;
 
         let start_time = start_time(timezone, start_time_str)?;
 
-        let mut post = PostBuilder::new(
+        let games = GuildHandler::enabled(pool, guild_id).await?;
+
+        // lfg_create_{template}_{game}
+        let mut custom_id = interaction.data.custom_id.splitn(4, '_').skip(2);
+
+        let template = custom_id
+            .next()
+            .and_then(|id| id.parse().ok())
+            .map(TemplateKind::from_id)
+            .unwrap_or_default();
+
+        let game = match custom_id.next() {
+            Some(id) => games
+                .iter()
+                .find(|game| game.id.eq_ignore_ascii_case(id))
+                .ok_or_else(|| Error::InvalidGame(id.to_string()))?,
+            None => games
+                .first()
+                .ok_or(Error::InvalidGame(DEFAULT_GAME.to_string()))?,
+        };
+
+        let custom = ActivityCatalog::load_custom::<Db, GuildHandler>(pool, guild_id).await?;
+        let activities = ActivityCatalog::with_custom(&game.id, &custom);
+        let resolved = resolve(&activities, activity);
+
+        let fireteam_size = validate_fireteam_size(resolved, fireteam_size)?;
+        let difficulty = parse_difficulty(resolved, inputs.remove("difficulty"))?;
+
+        let post = PostBuilder::new(
             interaction.user.id,
             activity,
             start_time,
             description,
-            fireteam_size as i16,
+            fireteam_size,
+        )
+        .guild(guild_id)
+        .game(game.id.clone())
+        .difficulty(difficulty)
+        .template(template);
+
+        let guild_template = template
+            .guild_template::<Db, GuildHandler>(pool, guild_id)
+            .await?;
+
+        let locale = guild_locale::<Db, GuildHandler>(
+            pool,
+            Some(guild_id),
+            interaction.guild_locale.as_deref(),
+        )
+        .await?;
+
+        let embed = template.thread_embed(
+            guild_template.as_ref(),
+            &post,
+            interaction.user.display_name(),
+            &locale,
         );
-
-        let embed = DefaultTemplate::thread_embed(&post, interaction.user.display_name());
-        let row = DefaultTemplate::main_row();
+        let row = template.main_row();
 
         let lfg_guild = GuildHandler::row(pool, guild_id)
-            .await
-            .unwrap()
+            .await?
             .ok_or(Error::MissingSetup)?;
 
         let channel = lfg_guild
             .channel_id()
             .to_channel(ctx)
-            .await
-            .unwrap()
+            .await?
             .guild()
-            .unwrap();
-
-        let tags = channel
-            .available_tags
-            .iter()
-            .filter(|tag| {
-                tag.name.to_lowercase()
-                    == ACTIVITIES
-                        .iter()
-                        .find(|a| activity.to_lowercase().contains(&a.name.to_lowercase()))
-                        .map(|a| a.category.to_string())
-                        .unwrap_or_default()
-                        .to_lowercase()
-            })
-            .map(|tag| tag.id);
+            .ok_or(Error::InvalidChannel)?;
+
+        let mappings = GuildHandler::tag_mappings(pool, guild_id).await?;
+
+        let mut tags = activity_tags(
+            &channel.available_tags,
+            &mappings,
+            &custom,
+            &game.id,
+            activity,
+            resolved,
+        );
+
+        // Tell games apart when the server organises more than one
+        if games.len() > 1 {
+            let game_tag = channel.available_tags.iter().find(|tag| {
+                tag.name.eq_ignore_ascii_case(&game.name) || tag.name.eq_ignore_ascii_case(&game.id)
+            });
+
+            if let Some(tag) = game_tag.filter(|tag| !tags.contains(&tag.id)) {
+                tags.push(tag.id);
+            }
+        }
+
+        let status_tags = GuildHandler::status_tags(pool, guild_id).await?;
+        let tags = with_status_tags(&tags, &status_tags, &post, Utc::now());
+
+        let base = format!("{} - {}", activity, start_time.format("%d %b %H:%M %Z"));
+
+        let name = match GuildHandler::title_format(pool, guild_id).await? {
+            Some(format) => format.apply(&base, &post),
+            None => base.clone(),
+        };
 
         let thread = match channel
             .create_forum_post(
                 ctx,
                 CreateForumPost::new(
-                    format!("{} - {}", activity, start_time.format("%d %b %H:%M %Z")),
+                    name,
                     CreateMessage::new().embed(embed).components(vec![row]),
                 )
                 .auto_archive_duration(AutoArchiveDuration::OneWeek)
@@ -133,7 +219,7 @@ impl Create {
             }))) => {
                 return Err(Error::TagRequired);
             }
-            r => r.unwrap(),
+            Err(e) => return Err(e.into()),
         };
 
         thread
@@ -141,31 +227,28 @@ impl Create {
                 ctx,
                 CreateMessage::new().content(interaction.user.mention().to_string()),
             )
-            .await
-            .unwrap();
-
-        if let Some(thread_id) = lfg_guild.scheduled_thread_id() {
-            let embed =
-                DefaultTemplate::message_embed(&post, interaction.user.display_name(), thread.id);
-
-            let msg = thread_id
-                .send_message(ctx, CreateMessage::new().embed(embed))
-                .await
-                .unwrap();
-
-            post = post.alt_channel(thread_id).alt_message(msg.id)
-        }
+            .await?;
 
         let post = post.id(thread.id).build();
 
         create_reminders::<Db, PostHandler>(ctx, &post).await;
+        schedule_status_tags::<Db, PostHandler>(ctx, &post).await;
 
-        PostHandler::save(pool, post).await.unwrap();
+        let entry = AuditEntry::new(post.channel(), AuditAction::Create)
+            .guild(Some(guild_id))
+            .actor(Some(interaction.user.id))
+            .after(snapshot(&post));
+
+        PostHandler::save(pool, post).await?;
+        PostHandler::record(pool, entry).await?;
+        GuildHandler::save_thread_base(pool, thread.id, base).await?;
+
+        // After the save, so the refresh finds the new post
+        sync_schedule::<Db, PostHandler>(ctx, Some(guild_id)).await;
 
         interaction
             .create_response(ctx, CreateInteractionResponse::Acknowledge)
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
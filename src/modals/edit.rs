@@ -1,20 +1,42 @@
-use serenity::all::{Context, CreateInteractionResponse, EditThread, ModalInteraction};
+use std::sync::Arc;
+
+use serenity::all::{Context, CreateInteractionResponse, ModalInteraction};
 use sqlx::{Database, Pool};
 use zayden_core::parse_modal_data;
 
-use crate::templates::DefaultTemplate;
-use crate::utils::update_embeds;
-use crate::{PostBuilder, PostManager, PostRow, Result, Savable, TimezoneManager};
+use crate::activities::resolve;
+use crate::cron::schedule_status_tags;
+use crate::locale::guild_locale;
+use crate::models::{AuditAction, AuditEntry, ModLog};
+use crate::templates::TemplateInfo;
+use crate::utils::{
+    Announcement, sync_schedule, sync_status_tags, sync_thread_title, update_embeds,
+};
+use crate::{
+    ActivityCatalog, AnnouncementManager, AuditManager, CustomActivityManager, Error,
+    LocaleManager, ModLogManager, PostBuilder, PostManager, PostRow, Result, Savable,
+    ScheduleManager, TagMappingManager, TemplateManager, ThreadTitleManager, TimezoneManager,
+};
 
-use super::start_time;
+use super::{parse_difficulty, start_time, validate_fireteam_size};
 
 pub struct Edit;
 
 impl Edit {
     pub async fn run<
         Db: Database,
-        Manager: PostManager<Db> + Savable<Db, PostRow>,
+        Manager: PostManager<Db>
+            + TagMappingManager<Db>
+            + TemplateManager<Db>
+            + ThreadTitleManager<Db>
+            + LocaleManager<Db>
+            + AuditManager<Db>
+            + AnnouncementManager<Db>
+            + ScheduleManager<Db>
+            + ModLogManager<Db>
+            + Savable<Db, PostRow>,
         TzManager: TimezoneManager<Db>,
+        GuildHandler: CustomActivityManager<Db>,
     >(
         ctx: &Context,
         interaction: &ModalInteraction,
@@ -27,9 +49,11 @@ impl Edit {
             .expect("Activity should exist as it's required");
         let fireteam_size = inputs
             .remove("fireteam size")
-            .expect("Fireteam size should exist as it's required")
+            .expect("Fireteam size should exist as it's required");
+        let fireteam_size = fireteam_size
+            .trim()
             .parse::<i16>()
-            .unwrap();
+            .map_err(|_| Error::InvalidNumber(fireteam_size.to_string()))?;
         let description = match inputs.remove("description") {
             Some(description) => description,
             _ => activity,
@@ -38,45 +62,118 @@ impl Edit {
             .remove("start time")
             .expect("Start time should exist as it's required");
 
-        let timezone = TzManager::get(pool, interaction.user.id, &interaction.locale)
-            .await
-            .unwrap();
+        let timezone = TzManager::get(pool, interaction.user.id, &interaction.locale).await?;
 
         let start_time = start_time(timezone, start_time_str)?;
 
-        let post = PostBuilder::from(Manager::row(pool, interaction.channel_id).await.unwrap())
+        let row = Manager::row(pool, interaction.channel_id).await?;
+
+        let custom = match row.guild().or(interaction.guild_id) {
+            Some(guild) => ActivityCatalog::load_custom::<Db, GuildHandler>(pool, guild).await?,
+            None => Arc::from([]),
+        };
+        let activities = ActivityCatalog::with_custom(row.game(), &custom);
+        let resolved = resolve(&activities, activity);
+
+        let fireteam_size = validate_fireteam_size(resolved, fireteam_size)?;
+        let difficulty = parse_difficulty(resolved, inputs.remove("difficulty"))?;
+
+        let post = PostBuilder::from(row.clone())
             .activity(activity)
+            .difficulty(difficulty)
             .fireteam_size(fireteam_size)
             .description(description)
             .start(start_time);
 
-        interaction
-            .channel_id
-            .edit_thread(
-                ctx,
-                EditThread::new().name(format!(
-                    "{} - {}",
-                    activity,
-                    start_time.format("%d %b %H:%M %Z")
-                )),
-            )
-            .await
-            .unwrap();
+        let locale = guild_locale::<Db, Manager>(
+            pool,
+            interaction.guild_id,
+            interaction.guild_locale.as_deref(),
+        )
+        .await?;
+
+        let entry = AuditEntry::new(interaction.channel_id, AuditAction::Edit)
+            .guild(interaction.guild_id)
+            .actor(Some(interaction.user.id))
+            .changes(&row, &post);
 
-        update_embeds::<DefaultTemplate>(
+        let post = post.build();
+        let rescheduled = post.timestamp() != row.timestamp();
+
+        Manager::save(pool, post.clone()).await?;
+        Manager::record(pool, entry).await?;
+
+        // The edit is saved, so a failure past here is logged rather than returned
+        let thread = interaction.channel_id;
+
+        if rescheduled {
+            schedule_status_tags::<Db, Manager>(ctx, &post).await;
+        }
+
+        match row.owner().to_user(ctx).await {
+            Ok(owner) => {
+                if let Err(e) = update_embeds::<Db, Manager>(
+                    ctx,
+                    pool,
+                    &post,
+                    owner.display_name(),
+                    thread,
+                    &locale,
+                )
+                .await
+                {
+                    eprintln!("Failed to update the embeds of '{thread}': {e:?}");
+                }
+            }
+            Err(e) => eprintln!("Failed to load the owner of '{thread}': {e:?}"),
+        }
+        if let Err(e) = sync_status_tags::<Db, Manager>(ctx, pool, &post, thread).await {
+            eprintln!("Failed to sync the status tags of '{thread}': {e:?}");
+        }
+        if let Err(e) = sync_thread_title::<Db, Manager>(
             ctx,
+            pool,
             &post,
-            interaction.user.display_name(),
-            interaction.channel_id,
+            thread,
+            Some(format!(
+                "{} - {}",
+                activity,
+                start_time.format("%d %b %H:%M %Z")
+            )),
         )
-        .await;
+        .await
+        {
+            eprintln!("Failed to rename '{thread}': {e:?}");
+        }
 
-        Manager::save(pool, post.build()).await.unwrap();
+        sync_schedule::<Db, Manager>(ctx, interaction.guild_id).await;
+
+        let announcement = if rescheduled {
+            Announcement::Rescheduled(post.timestamp())
+        } else {
+            Announcement::Edited(interaction.user.id)
+        };
+        if let Err(e) = announcement
+            .send::<Db, Manager>(ctx, pool, interaction.guild_id, thread, &locale)
+            .await
+        {
+            eprintln!("Failed to announce the edit in '{thread}': {e:?}");
+        }
+
+        if row.owner() != interaction.user.id {
+            ModLog::Override {
+                thread,
+                owner: row.owner(),
+                actor: interaction.user.id,
+                action: AuditAction::Edit,
+            }
+            .send::<Db, Manager>(ctx, pool, interaction.guild_id)
+            .await;
+        }
 
         interaction
             .create_response(ctx, CreateInteractionResponse::Acknowledge)
-            .await
-            .unwrap();
+            .await?;
 
         Ok(())
     }
@@ -4,41 +4,81 @@ pub use create::{Create, GuildManager};
 pub mod edit;
 pub use edit::Edit;
 
+pub mod template;
+pub use template::EditTemplate;
+
 use chrono::{DateTime, NaiveDateTime, TimeZone};
 use chrono_tz::Tz;
 use serenity::all::{CreateActionRow, CreateInputText, InputTextStyle};
 
-use crate::{Error, Result};
+use crate::locale::{message, message_with};
+use crate::{Activity, ActivityCatalog, Difficulty, Error, Result};
 
 pub fn modal_components(
+    game: &str,
     activity: &str,
     start_time: DateTime<Tz>,
     fireteam_size: i16,
     description: Option<&str>,
+    difficulty: Option<Difficulty>,
+    locale: &str,
 ) -> Vec<CreateActionRow> {
-    let mut desc_input =
-        CreateInputText::new(InputTextStyle::Paragraph, "Description", "description")
-            .required(false);
+    let mut desc_input = CreateInputText::new(
+        InputTextStyle::Paragraph,
+        message(locale, "modal.description"),
+        "description",
+    )
+    .required(false);
     desc_input = match description {
         Some(description) => desc_input.value(description),
         None => desc_input.placeholder(activity),
     };
 
+    let mut difficulty_input = CreateInputText::new(
+        InputTextStyle::Short,
+        message(locale, "modal.difficulty"),
+        "difficulty",
+    )
+    .required(false);
+    difficulty_input = match difficulty {
+        Some(difficulty) => difficulty_input.value(difficulty.to_string()),
+        None => match ActivityCatalog::find(game, activity) {
+            Some(activity) if !activity.difficulties.is_empty() => {
+                difficulty_input.placeholder(difficulties(&activity))
+            }
+            _ => difficulty_input,
+        },
+    };
+
     vec![
         CreateActionRow::InputText(
-            CreateInputText::new(InputTextStyle::Short, "Activity", "activity").value(activity),
+            CreateInputText::new(
+                InputTextStyle::Short,
+                message(locale, "modal.activity"),
+                "activity",
+            )
+            .value(activity),
         ),
+        CreateActionRow::InputText(difficulty_input),
         CreateActionRow::InputText(
             CreateInputText::new(
                 InputTextStyle::Short,
-                format!("Start Time ({})", start_time.format("%Z")),
+                message_with(
+                    locale,
+                    "modal.start_time",
+                    &[("timezone", &start_time.format("%Z"))],
+                ),
                 "start time",
             )
             .value(format!("{}", start_time.format("%Y-%m-%d %H:%M"))),
         ),
         CreateActionRow::InputText(
-            CreateInputText::new(InputTextStyle::Short, "Fireteam Size", "fireteam size")
-                .value(fireteam_size.to_string()),
+            CreateInputText::new(
+                InputTextStyle::Short,
+                message(locale, "modal.fireteam_size"),
+                "fireteam size",
+            )
+            .value(fireteam_size.to_string()),
         ),
         CreateActionRow::InputText(desc_input),
     ]
@@ -51,7 +91,62 @@ fn start_time(timezone: Tz, start_time_str: &str) -> Result<DateTime<Tz>> {
     let st = timezone
         .from_local_datetime(&naive_dt)
         .single()
-        .expect("Invalid date time");
+        .ok_or_else(|| Error::InvalidDateTime("YYYY-MM-DD HH:MM".to_string()))?;
 
     Ok(st)
 }
+
+fn parse_difficulty(
+    activity: Option<&Activity>,
+    input: Option<&str>,
+) -> Result<Option<Difficulty>> {
+    let Some(input) = input.filter(|input| !input.trim().is_empty()) else {
+        return Ok(None);
+    };
+
+    let expected = match activity {
+        Some(activity) if !activity.difficulties.is_empty() => difficulties(activity),
+        _ => Difficulty::ALL.map(|d| d.to_string()).join(", "),
+    };
+
+    let difficulty = input
+        .parse::<Difficulty>()
+        .map_err(|_| Error::InvalidDifficulty(expected.clone()))?;
+
+    match activity {
+        Some(activity)
+            if !activity.difficulties.is_empty()
+                && !activity.difficulties.contains(&difficulty) =>
+        {
+            Err(Error::InvalidDifficulty(expected))
+        }
+        _ => Ok(Some(difficulty)),
+    }
+}
+
+/// Checks the size against the activity's limits, or that it's at least 1 for activities
+/// outside the catalog.
+fn validate_fireteam_size(activity: Option<&Activity>, size: i16) -> Result<i16> {
+    match activity {
+        Some(activity) if !activity.supports_fireteam_size(size) => {
+            Err(Error::InvalidFireteamSize {
+                min: activity.min_fireteam_size,
+                max: activity.max_fireteam_size,
+            })
+        }
+        None if size < 1 => Err(Error::InvalidFireteamSize {
+            min: 1,
+            max: i16::MAX,
+        }),
+        _ => Ok(size),
+    }
+}
+
+fn difficulties(activity: &Activity) -> String {
+    activity
+        .difficulties
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,76 @@
+use chrono::{Duration, Utc};
+use chrono_tz::Tz;
+use serenity::all::{
+    Context, CreateInteractionResponse, CreateInteractionResponseMessage, ModalInteraction,
+};
+use sqlx::{Database, Pool};
+use zayden_core::parse_modal_data;
+
+use crate::locale::{LocaleManager, message};
+use crate::models::{ModLog, ModLogManager};
+use crate::templates::{GuildTemplateRow, Template, TemplateManager, TextTemplate};
+use crate::{Error, PostBuilder, Result};
+
+pub struct EditTemplate;
+
+impl EditTemplate {
+    pub async fn run<
+        Db: Database,
+        Manager: TemplateManager<Db> + ModLogManager<Db> + LocaleManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &ModalInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+
+        let mut inputs = parse_modal_data(&interaction.data.components);
+
+        let title = inputs
+            .remove("title")
+            .expect("Title should exist as it's required");
+
+        let template = TextTemplate::new(
+            title,
+            inputs.remove("description").unwrap_or_default(),
+            inputs.remove("colour"),
+            inputs.remove("thumbnail"),
+            inputs.remove("footer"),
+        )?;
+
+        Manager::save_guild_template(pool, guild_id, GuildTemplateRow::from(&template)).await?;
+
+        let sample = PostBuilder::new(
+            interaction.user.id,
+            "Vault of Glass",
+            (Utc::now() + Duration::hours(2)).with_timezone(&Tz::UTC),
+            "Fresh run, bring a relic runner",
+            6,
+        );
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(message(&interaction.locale, "response.template_saved"))
+                        .embed(template.thread_embed(
+                            &sample,
+                            interaction.user.display_name(),
+                            &interaction.locale,
+                        ))
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+
+        ModLog::SetupChanged {
+            actor: interaction.user.id,
+            change: format!("/lfg config template title: {}", template.title),
+        }
+        .send::<Db, Manager>(ctx, pool, Some(guild_id))
+        .await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serenity::all::{ChannelId, GuildId, MessageId};
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::locale::message;
+
+/// The most recent changes kept in an activity feed message.
+pub const FEED_LINES: usize = 10;
+
+#[async_trait]
+pub trait AnnouncementManager<Db: Database> {
+    async fn guild_announcements(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<AnnouncementMode>>;
+
+    async fn save_guild_announcements(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        mode: AnnouncementMode,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn post_announcements(
+        pool: &Pool<Db>,
+        id: impl Into<ChannelId> + Send,
+    ) -> sqlx::Result<Option<AnnouncementMode>>;
+
+    /// `None` makes the post follow the guild's mode again.
+    async fn save_post_announcements(
+        pool: &Pool<Db>,
+        id: impl Into<ChannelId> + Send,
+        mode: Option<AnnouncementMode>,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn feed_message(
+        pool: &Pool<Db>,
+        id: impl Into<ChannelId> + Send,
+    ) -> sqlx::Result<Option<MessageId>>;
+
+    async fn save_feed_message(
+        pool: &Pool<Db>,
+        id: impl Into<ChannelId> + Send,
+        message: MessageId,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+/// How a post's joins, leaves and changes are announced in its thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnnouncementMode {
+    Off,
+    /// A new message for every change.
+    #[default]
+    Each,
+    /// A single message, edited with the latest [`FEED_LINES`] changes.
+    Feed,
+}
+
+impl AnnouncementMode {
+    pub const ALL: [Self; 3] = [Self::Off, Self::Each, Self::Feed];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Each => "each",
+            Self::Feed => "feed",
+        }
+    }
+
+    /// The mode as it reads in replies, e.g. "Activity feed".
+    pub fn name(&self, locale: &str) -> String {
+        message(locale, &format!("announcement.mode_{}", self.as_str()))
+    }
+}
+
+impl Display for AnnouncementMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Off => "Off",
+            Self::Each => "One message per change",
+            Self::Feed => "Activity feed",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for AnnouncementMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "each" => Ok(Self::Each),
+            "feed" => Ok(Self::Feed),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serenity::all::{ChannelId, GuildId, Mentionable, UserId};
+use sqlx::prelude::FromRow;
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::locale::message;
+use crate::templates::TemplateInfo;
+
+/// Entries older than this are removed by [`crate::cron::prune_audit_log`].
+pub const AUDIT_RETENTION_DAYS: i64 = 90;
+
+#[async_trait]
+pub trait AuditManager<Db: Database> {
+    async fn record(pool: &Pool<Db>, entry: AuditEntry) -> sqlx::Result<AnyQueryResult>;
+
+    /// The post's entries, newest first.
+    async fn entries(
+        pool: &Pool<Db>,
+        post: impl Into<ChannelId> + Send,
+        limit: i64,
+    ) -> sqlx::Result<Vec<AuditRow>>;
+
+    /// Removes the entries created before `before`.
+    async fn prune(pool: &Pool<Db>, before: DateTime<Utc>) -> sqlx::Result<AnyQueryResult>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    Create,
+    Join,
+    Leave,
+    Kick,
+    Edit,
+    Tags,
+    Delete,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Join => "join",
+            Self::Leave => "leave",
+            Self::Kick => "kick",
+            Self::Edit => "edit",
+            Self::Tags => "tags",
+            Self::Delete => "delete",
+        }
+    }
+
+    /// The action as it reads in the audit log, e.g. "Joined".
+    pub fn name(&self, locale: &str) -> String {
+        message(locale, &format!("audit.{}", self.as_str()))
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create" => Ok(Self::Create),
+            "join" => Ok(Self::Join),
+            "leave" => Ok(Self::Leave),
+            "kick" => Ok(Self::Kick),
+            "edit" => Ok(Self::Edit),
+            "tags" => Ok(Self::Tags),
+            "delete" => Ok(Self::Delete),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A change to a post, waiting to be recorded.
+pub struct AuditEntry {
+    pub post_id: i64,
+    pub guild_id: Option<i64>,
+    pub actor_id: Option<i64>,
+    pub target_id: Option<i64>,
+    pub action: &'static str,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditEntry {
+    pub fn new(post: impl Into<ChannelId>, action: AuditAction) -> Self {
+        Self {
+            post_id: post.into().get() as i64,
+            guild_id: None,
+            actor_id: None,
+            target_id: None,
+            action: action.as_str(),
+            before: None,
+            after: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn guild(mut self, guild: Option<GuildId>) -> Self {
+        self.guild_id = guild.map(|id| id.get() as i64);
+        self
+    }
+
+    /// The user who made the change, `None` when Discord or the bot did.
+    pub fn actor(mut self, actor: Option<UserId>) -> Self {
+        self.actor_id = actor.map(|id| id.get() as i64);
+        self
+    }
+
+    /// The user the change was made to, if it isn't the actor.
+    pub fn target(mut self, target: impl Into<UserId>) -> Self {
+        self.target_id = Some(target.into().get() as i64);
+        self
+    }
+
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    pub fn after(mut self, after: impl Into<String>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// The fields that differ between the two versions of the post.
+    pub fn changes(mut self, before: &impl TemplateInfo, after: &impl TemplateInfo) -> Self {
+        let changes = [
+            (
+                "Activity",
+                before.activity().to_string(),
+                after.activity().to_string(),
+            ),
+            (
+                "Difficulty",
+                before
+                    .difficulty()
+                    .map(|d| d.to_string())
+                    .unwrap_or_default(),
+                after
+                    .difficulty()
+                    .map(|d| d.to_string())
+                    .unwrap_or_default(),
+            ),
+            (
+                "Start",
+                format!("<t:{}>", before.timestamp()),
+                format!("<t:{}>", after.timestamp()),
+            ),
+            (
+                "Size",
+                before.fireteam_size().to_string(),
+                after.fireteam_size().to_string(),
+            ),
+            (
+                "Description",
+                before.description().to_string(),
+                after.description().to_string(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, before, after)| before != after)
+        .collect::<Vec<_>>();
+
+        if changes.is_empty() {
+            return self;
+        }
+
+        let (before, after): (Vec<_>, Vec<_>) = changes
+            .iter()
+            .map(|(field, before, after)| {
+                (format!("{field}: {before}"), format!("{field}: {after}"))
+            })
+            .unzip();
+
+        self.before = Some(before.join("\n"));
+        self.after = Some(after.join("\n"));
+        self
+    }
+}
+
+#[derive(FromRow)]
+pub struct AuditRow {
+    pub id: i64,
+    pub post_id: i64,
+    pub guild_id: Option<i64>,
+    pub actor_id: Option<i64>,
+    pub target_id: Option<i64>,
+    pub action: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditRow {
+    pub fn actor(&self) -> Option<UserId> {
+        self.actor_id.map(|id| UserId::new(id as u64))
+    }
+
+    pub fn target(&self) -> Option<UserId> {
+        self.target_id.map(|id| UserId::new(id as u64))
+    }
+
+    pub fn action(&self) -> Option<AuditAction> {
+        self.action.parse().ok()
+    }
+}
+
+/// The post's details, roster and description, kept when it's created or deleted.
+pub fn snapshot(post: &impl TemplateInfo) -> String {
+    let mentions = |users: Vec<UserId>| {
+        if users.is_empty() {
+            return String::from("-");
+        }
+
+        users
+            .iter()
+            .map(|user| user.mention().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut lines = vec![format!("Activity: {}", post.activity())];
+
+    if let Some(difficulty) = post.difficulty() {
+        lines.push(format!("Difficulty: {difficulty}"));
+    }
+
+    lines.extend([
+        format!("Start: <t:{}>", post.timestamp()),
+        format!("Size: {}", post.fireteam_size()),
+        format!("Fireteam: {}", mentions(post.fireteam().collect())),
+        format!("Alternatives: {}", mentions(post.alternatives().collect())),
+        format!("Description: {}", post.description()),
+    ]);
+
+    lines.join("\n")
+}
+
+/// The cut-off for [`AuditManager::prune`] that keeps [`AUDIT_RETENTION_DAYS`] of entries.
+pub fn retention_cutoff() -> DateTime<Utc> {
+    Utc::now() - Duration::days(AUDIT_RETENTION_DAYS)
+}
@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use serenity::all::{GuildId, UserId};
+use sqlx::prelude::FromRow;
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+/// The tokens of the subscribable calendar feeds. A token gives access to the user's feed, or to
+/// the guild's feed when the row has a guild.
+#[async_trait]
+pub trait CalendarTokenManager<Db: Database> {
+    async fn token(pool: &Pool<Db>, token: &str) -> sqlx::Result<Option<CalendarTokenRow>>;
+
+    /// Replaces the user's token for the feed.
+    async fn save_token(pool: &Pool<Db>, row: CalendarTokenRow) -> sqlx::Result<AnyQueryResult>;
+
+    /// Revokes the user's token for their own feed, or for the guild's feed when given.
+    async fn revoke_token(
+        pool: &Pool<Db>,
+        user: impl Into<UserId> + Send,
+        guild: Option<GuildId>,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+#[derive(FromRow)]
+pub struct CalendarTokenRow {
+    pub token: String,
+    pub user_id: i64,
+    pub guild_id: Option<i64>,
+}
+
+impl CalendarTokenRow {
+    pub fn new(token: impl Into<String>, user: impl Into<UserId>, guild: Option<GuildId>) -> Self {
+        Self {
+            token: token.into(),
+            user_id: user.into().get() as i64,
+            guild_id: guild.map(|guild| guild.get() as i64),
+        }
+    }
+
+    pub fn user(&self) -> UserId {
+        UserId::new(self.user_id as u64)
+    }
+
+    pub fn guild(&self) -> Option<GuildId> {
+        self.guild_id.map(|id| GuildId::new(id as u64))
+    }
+}
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use serenity::all::GuildId;
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::ActivityCatalog;
+use crate::activities::{DEFAULT_GAME, Game};
+
+#[async_trait]
+pub trait GameManager<Db: Database>: Send + Sync {
+    /// The ids of the games enabled for the guild.
+    async fn games(pool: &Pool<Db>, id: impl Into<GuildId> + Send) -> sqlx::Result<Vec<String>>;
+
+    async fn save_games(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        games: Vec<String>,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    /// The catalogs of the games enabled for the guild, falling back to Destiny 2 when none are.
+    async fn enabled(pool: &Pool<Db>, id: impl Into<GuildId> + Send) -> sqlx::Result<Vec<Game>> {
+        let ids = Self::games(pool, id).await?;
+
+        let games = ActivityCatalog::games()
+            .iter()
+            .filter(|game| ids.iter().any(|id| game.id.eq_ignore_ascii_case(id)))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if games.is_empty() {
+            return Ok(ActivityCatalog::game(DEFAULT_GAME).into_iter().collect());
+        }
+
+        Ok(games)
+    }
+}
@@ -1,10 +1,26 @@
+pub mod announcement;
+pub mod audit;
+pub mod calendar_token;
+pub mod game_manager;
+pub mod mod_log;
 pub mod post;
+pub mod schedule;
+pub mod tag_mapping;
+pub mod thread_title;
 pub mod timezone_manager;
 
+pub use announcement::{AnnouncementManager, AnnouncementMode};
 use async_trait::async_trait;
+pub use audit::{AuditAction, AuditEntry, AuditManager, AuditRow};
+pub use calendar_token::{CalendarTokenManager, CalendarTokenRow};
+pub use game_manager::GameManager;
+pub use mod_log::{ModLog, ModLogManager};
 pub use post::{PostBuilder, PostManager, PostRow};
+pub use schedule::ScheduleManager;
 use serenity::all::UserId;
 use sqlx::{Database, Pool};
+pub use tag_mapping::{StatusTag, StatusTagRow, TagMappingManager, TagMappingRow};
+pub use thread_title::{ThreadTitleManager, ThreadTitleRow};
 pub use timezone_manager::TimezoneManager;
 
 use crate::{Error, Result};
@@ -0,0 +1,249 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serenity::all::{
+    ChannelId, Colour, Context, CreateEmbed, CreateMessage, GuildId, Mentionable, UserId,
+};
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::PostRow;
+use crate::locale::{DEFAULT_LOCALE, LocaleManager, guild_locale, message, message_with};
+use crate::models::AuditAction;
+use crate::templates::TemplateInfo;
+
+/// Discord's limit on an embed field value.
+const FIELD_LIMIT: usize = 1024;
+
+#[async_trait]
+pub trait ModLogManager<Db: Database> {
+    async fn log_channel(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<ChannelId>>;
+
+    async fn save_log_channel(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        channel: Option<ChannelId>,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+/// An action posted to the guild's moderation log channel.
+pub enum ModLog<'a> {
+    /// `actor` is `None` when the thread was deleted in Discord.
+    PostDeleted {
+        post: &'a PostRow,
+        actor: Option<UserId>,
+    },
+    Kicked {
+        thread: ChannelId,
+        user: UserId,
+        owner: UserId,
+        actor: UserId,
+    },
+    /// A moderator acting on a post they don't own.
+    Override {
+        thread: ChannelId,
+        owner: UserId,
+        actor: UserId,
+        action: AuditAction,
+    },
+    SetupChanged {
+        actor: UserId,
+        change: String,
+    },
+}
+
+impl ModLog<'_> {
+    /// Posts the embed to the guild's log channel, if it has one.
+    ///
+    /// A missing or unusable log channel never fails the action being logged.
+    pub async fn send<Db: Database, Manager: ModLogManager<Db> + LocaleManager<Db>>(
+        &self,
+        ctx: &Context,
+        pool: &Pool<Db>,
+        guild: Option<GuildId>,
+    ) {
+        let Some(guild) = guild else {
+            return;
+        };
+
+        let channel = match Manager::log_channel(pool, guild).await {
+            Ok(Some(channel)) => channel,
+            Ok(None) => return,
+            Err(e) => {
+                eprintln!("Failed to load the log channel of '{guild}': {e:?}");
+                return;
+            }
+        };
+
+        // The log is read by the guild's moderators, so it follows the guild's language
+        let locale = match guild_locale::<Db, Manager>(pool, Some(guild), None).await {
+            Ok(locale) => locale,
+            Err(e) => {
+                eprintln!("Failed to load the locale of '{guild}': {e:?}");
+                DEFAULT_LOCALE.to_string()
+            }
+        };
+
+        if let Err(e) = channel
+            .send_message(ctx, CreateMessage::new().embed(self.embed(&locale)))
+            .await
+        {
+            eprintln!("Failed to post to the log channel of '{guild}': {e:?}");
+        }
+    }
+
+    pub fn embed(&self, locale: &str) -> CreateEmbed {
+        let embed = CreateEmbed::new().timestamp(Utc::now());
+
+        match self {
+            Self::PostDeleted { post, actor } => {
+                let owner = post.owner();
+
+                let deleted_by = match actor {
+                    Some(actor) if *actor == owner => actor.mention().to_string(),
+                    Some(actor) => moderator(locale, *actor),
+                    None => message(locale, "mod_log.thread_deleted"),
+                };
+
+                let mut embed = embed
+                    .title(message(locale, "mod_log.post_deleted"))
+                    .colour(Colour::RED)
+                    .field(message(locale, "embed.activity"), post.activity(), true)
+                    .field(
+                        message(locale, "mod_log.start"),
+                        format!("<t:{}>", post.timestamp()),
+                        true,
+                    )
+                    .field(
+                        message(locale, "mod_log.thread"),
+                        format!("`{}`", post.channel()),
+                        true,
+                    )
+                    .field(
+                        message(locale, "mod_log.owner"),
+                        owner.mention().to_string(),
+                        true,
+                    )
+                    .field(message(locale, "mod_log.deleted_by"), deleted_by, true)
+                    .field(
+                        message_with(
+                            locale,
+                            "mod_log.fireteam",
+                            &[
+                                ("joined", &post.fireteam().count()),
+                                ("size", &post.fireteam_size()),
+                            ],
+                        ),
+                        users(post.fireteam()),
+                        false,
+                    );
+
+                if post.alternatives().next().is_some() {
+                    embed = embed.field(
+                        message(locale, "embed.alternatives"),
+                        users(post.alternatives()),
+                        false,
+                    );
+                }
+
+                if !post.description().is_empty() {
+                    embed = embed.field(
+                        message(locale, "embed.description"),
+                        truncate(post.description()),
+                        false,
+                    );
+                }
+
+                embed
+            }
+            Self::Kicked {
+                thread,
+                user,
+                owner,
+                actor,
+            } => {
+                let kicked_by = if actor == owner {
+                    actor.mention().to_string()
+                } else {
+                    moderator(locale, *actor)
+                };
+
+                embed
+                    .title(message(locale, "mod_log.kicked"))
+                    .colour(Colour::ORANGE)
+                    .field(
+                        message(locale, "mod_log.user"),
+                        user.mention().to_string(),
+                        true,
+                    )
+                    .field(
+                        message(locale, "mod_log.thread"),
+                        thread.mention().to_string(),
+                        true,
+                    )
+                    .field(message(locale, "mod_log.kicked_by"), kicked_by, true)
+            }
+            Self::Override {
+                thread,
+                owner,
+                actor,
+                action,
+            } => embed
+                .title(message(locale, "mod_log.override"))
+                .colour(Colour::GOLD)
+                .description(message_with(
+                    locale,
+                    "mod_log.used",
+                    &[
+                        ("actor", &actor.mention()),
+                        (
+                            "action",
+                            &message(locale, &format!("mod_log.{}", action.as_str())),
+                        ),
+                        ("thread", &thread.mention()),
+                    ],
+                ))
+                .field(
+                    message(locale, "mod_log.owner"),
+                    owner.mention().to_string(),
+                    true,
+                ),
+            Self::SetupChanged { actor, change } => embed
+                .title(message(locale, "mod_log.settings_changed"))
+                .colour(Colour::BLUE)
+                .description(truncate(change))
+                .field(
+                    message(locale, "mod_log.changed_by"),
+                    actor.mention().to_string(),
+                    true,
+                ),
+        }
+    }
+}
+
+fn moderator(locale: &str, user: UserId) -> String {
+    message_with(locale, "mod_log.moderator", &[("user", &user.mention())])
+}
+
+fn users(users: impl Iterator<Item = UserId>) -> String {
+    let users = users
+        .map(|user| user.mention().to_string())
+        .collect::<Vec<_>>();
+
+    if users.is_empty() {
+        return String::from("-");
+    }
+
+    truncate(&users.join(", "))
+}
+
+fn truncate(value: &str) -> String {
+    if value.chars().count() <= FIELD_LIMIT {
+        return value.to_string();
+    }
+
+    let mut value = value.chars().take(FIELD_LIMIT - 1).collect::<String>();
+    value.push('…');
+    value
+}
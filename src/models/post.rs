@@ -1,17 +1,22 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
-use serenity::all::{ChannelId, MessageId, UserId};
+use serenity::all::{ChannelId, GuildId, MessageId, UserId};
 use sqlx::prelude::FromRow;
 use sqlx::{Database, Pool, any::AnyQueryResult};
 
-use crate::templates::TemplateInfo;
-use crate::{Join, Leave};
+use crate::activities::DEFAULT_GAME;
+use crate::templates::{TemplateInfo, TemplateKind};
+use crate::{Difficulty, Join, Leave};
 
 pub struct PostBuilder {
     id: ChannelId,
+    guild: Option<GuildId>,
     owner: UserId,
+    game: String,
     activity: String,
+    difficulty: Option<Difficulty>,
+    template: TemplateKind,
     start_time: DateTime<Tz>,
     description: String,
     fireteam_size: i16,
@@ -33,8 +38,12 @@ impl PostBuilder {
 
         Self {
             id: ChannelId::default(),
+            guild: None,
             owner,
+            game: DEFAULT_GAME.to_string(),
             activity: activity.into(),
+            difficulty: None,
+            template: TemplateKind::default(),
             start_time: start,
             description: desc.into(),
             fireteam_size,
@@ -50,11 +59,31 @@ impl PostBuilder {
         self
     }
 
+    pub fn guild(mut self, guild: impl Into<GuildId>) -> Self {
+        self.guild = Some(guild.into());
+        self
+    }
+
+    pub fn game(mut self, game: impl Into<String>) -> Self {
+        self.game = game.into();
+        self
+    }
+
     pub fn activity(mut self, activity: impl Into<String>) -> Self {
         self.activity = activity.into();
         self
     }
 
+    pub fn difficulty(mut self, difficulty: Option<Difficulty>) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    pub fn template(mut self, template: TemplateKind) -> Self {
+        self.template = template;
+        self
+    }
+
     pub fn fireteam_size(mut self, size: i16) -> Self {
         self.fireteam_size = size;
         self
@@ -83,8 +112,12 @@ impl PostBuilder {
     pub fn build(self) -> PostRow {
         PostRow {
             id: self.id.get() as i64,
+            guild_id: self.guild.map(|guild| guild.get() as i64),
             owner: self.owner.get() as i64,
+            game: Some(self.game),
             activity: self.activity,
+            difficulty: self.difficulty.map(|difficulty| difficulty.to_string()),
+            template: Some(self.template.id()),
             start_time: self.start_time.with_timezone(&Utc),
             description: self.description,
             fireteam_size: self.fireteam_size,
@@ -105,10 +138,22 @@ impl PostBuilder {
 }
 
 impl TemplateInfo for PostBuilder {
+    fn game(&self) -> &str {
+        &self.game
+    }
+
     fn activity(&self) -> &str {
         &self.activity
     }
 
+    fn difficulty(&self) -> Option<Difficulty> {
+        self.difficulty
+    }
+
+    fn template(&self) -> TemplateKind {
+        self.template
+    }
+
     fn timestamp(&self) -> i64 {
         self.start_time.timestamp()
     }
@@ -136,13 +181,21 @@ impl TemplateInfo for PostBuilder {
     fn alt_message(&self) -> Option<MessageId> {
         self.alt_message
     }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        self.guild
+    }
 }
 
 impl From<PostRow> for PostBuilder {
     fn from(value: PostRow) -> Self {
         Self {
             id: ChannelId::new(value.id as u64),
+            guild: value.guild(),
             owner: UserId::new(value.owner as u64),
+            game: TemplateInfo::game(&value).to_string(),
+            difficulty: value.difficulty(),
+            template: value.template(),
             activity: value.activity,
             start_time: value.start_time.with_timezone(&Tz::UTC),
             description: value.description,
@@ -171,18 +224,38 @@ pub trait PostManager<Db: Database> {
 
     async fn row(pool: &Pool<Db>, id: impl Into<ChannelId> + Send) -> sqlx::Result<PostRow>;
 
+    /// The guild's posts starting from `start`, until `end` when given, soonest first.
+    async fn guild_posts(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        start: DateTime<Utc>,
+        end: Option<DateTime<Utc>>,
+    ) -> sqlx::Result<Vec<PostRow>>;
+
+    /// Stores the guild of a post created before posts were stored with their guild.
+    async fn save_guild(
+        pool: &Pool<Db>,
+        id: impl Into<ChannelId> + Send,
+        guild: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult>;
+
     async fn delete(
         pool: &Pool<Db>,
         id: impl Into<ChannelId> + Send,
     ) -> sqlx::Result<AnyQueryResult>;
 }
 
-#[derive(FromRow)]
-
+#[derive(Clone, FromRow)]
 pub struct PostRow {
     pub id: i64,
+    /// `None` for posts created before posts were stored with their guild, until the guild is
+    /// next loaded.
+    pub guild_id: Option<i64>,
     pub owner: i64,
+    pub game: Option<String>,
     pub activity: String,
+    pub difficulty: Option<String>,
+    pub template: Option<i16>,
     pub start_time: DateTime<Utc>,
     pub description: String,
     pub fireteam_size: i16,
@@ -204,6 +277,10 @@ impl PostRow {
     pub fn owner(&self) -> UserId {
         UserId::new(self.owner as u64)
     }
+
+    pub fn guild(&self) -> Option<GuildId> {
+        self.guild_id.map(|id| GuildId::new(id as u64))
+    }
 }
 
 impl Leave for PostRow {
@@ -235,10 +312,22 @@ impl Join for PostRow {
 }
 
 impl TemplateInfo for PostRow {
+    fn game(&self) -> &str {
+        self.game.as_deref().unwrap_or(DEFAULT_GAME)
+    }
+
     fn activity(&self) -> &str {
         &self.activity
     }
 
+    fn difficulty(&self) -> Option<Difficulty> {
+        self.difficulty.as_deref().and_then(|d| d.parse().ok())
+    }
+
+    fn template(&self) -> TemplateKind {
+        self.template.map(TemplateKind::from_id).unwrap_or_default()
+    }
+
     fn timestamp(&self) -> i64 {
         self.start_time.timestamp()
     }
@@ -266,4 +355,8 @@ impl TemplateInfo for PostRow {
     fn alt_message(&self) -> Option<MessageId> {
         self.alt_message.map(|id| MessageId::new(id as u64))
     }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        self.guild()
+    }
 }
@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use serenity::all::{
+    ChannelId, CreateActionRow, CreateEmbed, CreateEmbedFooter, GuildId, Mentionable, MessageId,
+};
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::PostRow;
+use crate::locale::{message, message_with};
+use crate::templates::{DefaultTemplate, Template, TemplateInfo};
+
+/// How many days ahead the board shows.
+pub const BOARD_DAYS: i64 = 7;
+/// The most posts in one board message, as each gets a row of buttons.
+pub const BOARD_POSTS_PER_MESSAGE: usize = 5;
+/// The longest a button label can be.
+const LABEL_LIMIT: usize = 80;
+
+/// The messages of the guild's schedule board, kept in the guild's scheduled thread.
+#[async_trait]
+pub trait ScheduleManager<Db: Database> {
+    /// The guild's scheduled thread, if it has one.
+    async fn scheduled_thread(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<ChannelId>>;
+
+    /// The timezone the board splits its days in, if the guild set one. Otherwise the board uses
+    /// the timezone of the guild's language, or UTC.
+    async fn board_timezone(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<Tz>>;
+
+    /// The board's messages, in order.
+    async fn board_messages(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Vec<MessageId>>;
+
+    async fn save_board_messages(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        messages: Vec<MessageId>,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+/// The board's messages, as an embed and the buttons of each of its posts. Each covers a day,
+/// or part of a busy day.
+///
+/// `posts` must be in chronological order.
+pub fn board(
+    posts: &[PostRow],
+    locale: &str,
+    timezone: Tz,
+) -> Vec<(CreateEmbed, Vec<CreateActionRow>)> {
+    if posts.is_empty() {
+        return vec![(
+            CreateEmbed::new()
+                .title(message(locale, "schedule.title"))
+                .description(message_with(
+                    locale,
+                    "schedule.empty",
+                    &[("days", &BOARD_DAYS)],
+                )),
+            Vec::new(),
+        )];
+    }
+
+    let mut days: Vec<(NaiveDate, Vec<&PostRow>)> = Vec::new();
+
+    for post in posts {
+        let date = post.start_time.with_timezone(&timezone).date_naive();
+
+        match days.last_mut() {
+            Some((day, posts)) if *day == date => posts.push(post),
+            _ => days.push((date, vec![post])),
+        }
+    }
+
+    let footer = message_with(locale, "schedule.footer", &[("timezone", &timezone.name())]);
+
+    days.iter()
+        .flat_map(|(date, day)| {
+            let title = day_title(*date, locale);
+            let footer = footer.clone();
+
+            day.chunks(BOARD_POSTS_PER_MESSAGE)
+                .enumerate()
+                .map(move |(i, posts)| {
+                    let title = if i == 0 {
+                        title.clone()
+                    } else {
+                        message_with(locale, "schedule.continued", &[("date", &title)])
+                    };
+
+                    let embed = CreateEmbed::new()
+                        .title(title)
+                        .description(
+                            posts
+                                .iter()
+                                .map(|post| entry(post, locale))
+                                .collect::<Vec<_>>()
+                                .join("\n"),
+                        )
+                        .footer(CreateEmbedFooter::new(footer.clone()));
+
+                    let rows = posts
+                        .iter()
+                        .map(|post| {
+                            let label = post
+                                .activity()
+                                .chars()
+                                .take(LABEL_LIMIT)
+                                .collect::<String>();
+                            DefaultTemplate::mirror_row(post.channel(), Some(&label))
+                        })
+                        .collect();
+
+                    (embed, rows)
+                })
+        })
+        .collect()
+}
+
+/// The day in the locale, e.g. "Monday 5 October".
+///
+/// The catalogs list the names comma separated, from Monday and January.
+fn day_title(date: NaiveDate, locale: &str) -> String {
+    let weekdays = message(locale, "schedule.weekdays");
+    let months = message(locale, "schedule.months");
+
+    let weekday = weekdays
+        .split(',')
+        .nth(date.weekday().num_days_from_monday() as usize)
+        .unwrap_or_default();
+    let month = months
+        .split(',')
+        .nth(date.month0() as usize)
+        .unwrap_or_default();
+
+    message_with(
+        locale,
+        "schedule.date",
+        &[
+            ("weekday", &weekday),
+            ("day", &date.day()),
+            ("month", &month),
+        ],
+    )
+}
+
+fn entry(post: &PostRow, locale: &str) -> String {
+    let activity = match post.difficulty() {
+        Some(difficulty) => format!("{} ({difficulty})", post.activity()),
+        None => post.activity().to_string(),
+    };
+
+    let mut slots = format!("{}/{}", post.fireteam().count(), post.fireteam_size());
+    if post.open_slots() == 0 {
+        slots.push(' ');
+        slots.push_str(&message(locale, "schedule.full"));
+    }
+
+    format!(
+        "<t:{}:t> **{activity}** · {slots} · {}",
+        post.timestamp(),
+        post.channel().mention()
+    )
+}
@@ -0,0 +1,323 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serenity::all::{ForumTag, ForumTagId, GuildId};
+use sqlx::prelude::FromRow;
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::locale::message;
+use crate::templates::TemplateInfo;
+use crate::{Activity, ActivityCategory, CustomActivityRow, Difficulty};
+
+#[async_trait]
+pub trait TagMappingManager<Db: Database> {
+    async fn tag_mappings(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Vec<TagMappingRow>>;
+
+    /// Inserts the mapping, replacing any existing mapping for the same game and category or
+    /// activity.
+    async fn save_tag_mapping(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        row: TagMappingRow,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn delete_tag_mapping(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        game: &str,
+        category: Option<&str>,
+        activity: Option<&str>,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn status_tags(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Vec<StatusTagRow>>;
+
+    /// Inserts the status tag, replacing any existing tag for the same status.
+    async fn save_status_tag(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        row: StatusTagRow,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn delete_status_tag(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        status: &str,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+/// The forum tags to apply to posts for a category or, when `activity` is set, a single activity.
+#[derive(FromRow)]
+pub struct TagMappingRow {
+    pub game: String,
+    pub category: Option<String>,
+    pub activity: Option<String>,
+    pub tags: Vec<i64>,
+}
+
+impl TagMappingRow {
+    pub fn category(
+        game: impl Into<String>,
+        category: &ActivityCategory,
+        tags: impl IntoIterator<Item = ForumTagId>,
+    ) -> Self {
+        Self {
+            game: game.into(),
+            category: Some(category.to_string()),
+            activity: None,
+            tags: tags.into_iter().map(|id| id.get() as i64).collect(),
+        }
+    }
+
+    pub fn activity(
+        game: impl Into<String>,
+        activity: impl Into<String>,
+        tags: impl IntoIterator<Item = ForumTagId>,
+    ) -> Self {
+        Self {
+            game: game.into(),
+            category: None,
+            activity: Some(activity.into()),
+            tags: tags.into_iter().map(|id| id.get() as i64).collect(),
+        }
+    }
+
+    pub fn tags(&self) -> impl Iterator<Item = ForumTagId> {
+        self.tags.iter().map(|&id| ForumTagId::new(id as u64))
+    }
+}
+
+/// The forum tags for a post about `activity`.
+///
+/// Uses the first of these that matches a tag still available in the forum:
+/// 1. The activity's tag mapping
+/// 2. The custom activity's tag
+/// 3. The category's tag mapping
+/// 4. Tags named after the category
+pub fn activity_tags(
+    available: &[ForumTag],
+    mappings: &[TagMappingRow],
+    custom: &[CustomActivityRow],
+    game: &str,
+    activity: &str,
+    resolved: Option<&Activity>,
+) -> Vec<ForumTagId> {
+    let available_ids = |ids: Vec<ForumTagId>| {
+        ids.into_iter()
+            .filter(|id| available.iter().any(|tag| tag.id == *id))
+            .collect::<Vec<_>>()
+    };
+
+    let mappings = mappings
+        .iter()
+        .filter(|row| row.game.eq_ignore_ascii_case(game))
+        .collect::<Vec<_>>();
+
+    let name = resolved.map(|a| &*a.name).unwrap_or(activity);
+
+    let activity_mapping = mappings
+        .iter()
+        .filter(|row| {
+            row.activity
+                .as_deref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(name))
+        })
+        .flat_map(|row| row.tags())
+        .collect();
+
+    let tags = available_ids(activity_mapping);
+    if !tags.is_empty() {
+        return tags;
+    }
+
+    let custom_tag = custom
+        .iter()
+        .filter(|row| row.game().eq_ignore_ascii_case(game))
+        .find(|row| row.name.eq_ignore_ascii_case(name))
+        .and_then(|row| row.tag_id())
+        .into_iter()
+        .collect();
+
+    let tags = available_ids(custom_tag);
+    if !tags.is_empty() {
+        return tags;
+    }
+
+    let Some(category) = resolved.map(|a| &a.category) else {
+        return Vec::new();
+    };
+
+    let category_mapping = mappings
+        .iter()
+        .filter(|row| {
+            row.category
+                .as_deref()
+                .is_some_and(|c| c.eq_ignore_ascii_case(category.name()))
+        })
+        .flat_map(|row| row.tags())
+        .collect();
+
+    let tags = available_ids(category_mapping);
+    if !tags.is_empty() {
+        return tags;
+    }
+
+    available
+        .iter()
+        .filter(|tag| tag.name.eq_ignore_ascii_case(category.name()))
+        .map(|tag| tag.id)
+        .collect()
+}
+
+/// A forum tag kept in sync with the state of a post.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusTag {
+    Full,
+    Lfm,
+    Master,
+    Contest,
+    Today,
+    ThisWeek,
+}
+
+impl StatusTag {
+    pub const ALL: [StatusTag; 6] = [
+        StatusTag::Full,
+        StatusTag::Lfm,
+        StatusTag::Master,
+        StatusTag::Contest,
+        StatusTag::Today,
+        StatusTag::ThisWeek,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            StatusTag::Full => "full",
+            StatusTag::Lfm => "lfm",
+            StatusTag::Master => "master",
+            StatusTag::Contest => "contest",
+            StatusTag::Today => "today",
+            StatusTag::ThisWeek => "this_week",
+        }
+    }
+
+    /// The status as it reads in replies, e.g. "This week".
+    pub fn name(&self, locale: &str) -> String {
+        message(locale, &format!("status_tag.{}", self.id()))
+    }
+
+    /// The statuses of the post. "Today" covers the next 24 hours and "This week" the next 7 days.
+    pub fn for_post(post: &impl TemplateInfo, now: DateTime<Utc>) -> Vec<StatusTag> {
+        let mut statuses = Vec::new();
+
+        if post.open_slots() == 0 {
+            statuses.push(StatusTag::Full);
+        } else {
+            statuses.push(StatusTag::Lfm);
+        }
+
+        match post.difficulty() {
+            Some(Difficulty::Master) => statuses.push(StatusTag::Master),
+            Some(Difficulty::Contest) => statuses.push(StatusTag::Contest),
+            _ => {}
+        }
+
+        let Some(start) = DateTime::from_timestamp(post.timestamp(), 0) else {
+            return statuses;
+        };
+
+        if start >= now && start < now + Duration::days(1) {
+            statuses.push(StatusTag::Today);
+        } else if start >= now && start < now + Duration::days(7) {
+            statuses.push(StatusTag::ThisWeek);
+        }
+
+        statuses
+    }
+}
+
+impl Display for StatusTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatusTag::Full => write!(f, "Full"),
+            StatusTag::Lfm => write!(f, "LFM"),
+            StatusTag::Master => write!(f, "Master"),
+            StatusTag::Contest => write!(f, "Contest"),
+            StatusTag::Today => write!(f, "Today"),
+            StatusTag::ThisWeek => write!(f, "This week"),
+        }
+    }
+}
+
+impl FromStr for StatusTag {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StatusTag::ALL
+            .into_iter()
+            .find(|status| status.id() == s || status.to_string().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
+}
+
+#[derive(FromRow)]
+pub struct StatusTagRow {
+    pub status: String,
+    pub tag_id: i64,
+}
+
+impl StatusTagRow {
+    pub fn new(status: StatusTag, tag_id: ForumTagId) -> Self {
+        Self {
+            status: status.id().to_string(),
+            tag_id: tag_id.get() as i64,
+        }
+    }
+
+    pub fn status(&self) -> Option<StatusTag> {
+        self.status.parse().ok()
+    }
+
+    pub fn tag_id(&self) -> ForumTagId {
+        ForumTagId::new(self.tag_id as u64)
+    }
+}
+
+/// `applied` with the status tags replaced by the current statuses of the post.
+///
+/// Other tags are kept first so status tags are the ones dropped at the 5 tag limit.
+pub fn with_status_tags(
+    applied: &[ForumTagId],
+    rows: &[StatusTagRow],
+    post: &impl TemplateInfo,
+    now: DateTime<Utc>,
+) -> Vec<ForumTagId> {
+    let statuses = StatusTag::for_post(post, now);
+
+    let mut tags = applied
+        .iter()
+        .copied()
+        .filter(|id| !rows.iter().any(|row| row.tag_id() == *id))
+        .collect::<Vec<_>>();
+
+    for row in rows {
+        if row
+            .status()
+            .is_some_and(|status| statuses.contains(&status))
+            && !tags.contains(&row.tag_id())
+        {
+            tags.push(row.tag_id());
+        }
+    }
+
+    tags.truncate(5);
+
+    tags
+}
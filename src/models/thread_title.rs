@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use serenity::all::{ChannelId, GuildId};
+use sqlx::prelude::FromRow;
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::Result;
+use crate::templates::TemplateInfo;
+use crate::templates::text::{render, validate};
+
+/// The placeholders available in a thread title prefix.
+pub const TITLE_PLACEHOLDERS: [&str; 3] = ["joined", "fireteam_size", "slots_left"];
+
+pub const DEFAULT_TITLE_FORMAT: &str = "[{joined}/{fireteam_size}]";
+pub const DEFAULT_TITLE_FULL: &str = "FULL";
+
+/// Discord only allows this many thread renames per [`RENAME_WINDOW_SECS`].
+pub const RENAME_LIMIT: usize = 2;
+pub const RENAME_WINDOW_SECS: i64 = 600;
+
+#[async_trait]
+pub trait ThreadTitleManager<Db: Database> {
+    async fn title_format(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<ThreadTitleRow>>;
+
+    async fn save_title_format(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        row: ThreadTitleRow,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn delete_title_format(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    /// The thread's name without the slot count prefix, e.g. "Vault of Glass - 12 Oct 20:00 BST".
+    async fn thread_base(
+        pool: &Pool<Db>,
+        id: impl Into<ChannelId> + Send,
+    ) -> sqlx::Result<Option<String>>;
+
+    async fn save_thread_base(
+        pool: &Pool<Db>,
+        id: impl Into<ChannelId> + Send,
+        base: String,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+/// The slot count prefix of a guild's thread titles, e.g. "[3/6] Vault of Glass - ...".
+///
+/// `full` replaces the prefix once the fireteam is full.
+#[derive(Debug, Clone, FromRow)]
+pub struct ThreadTitleRow {
+    pub format: String,
+    pub full: String,
+}
+
+impl ThreadTitleRow {
+    pub fn new(format: Option<&str>, full: Option<&str>) -> Result<Self> {
+        let format = format
+            .map(str::trim)
+            .filter(|format| !format.is_empty())
+            .unwrap_or(DEFAULT_TITLE_FORMAT);
+        let full = full
+            .map(str::trim)
+            .filter(|full| !full.is_empty())
+            .unwrap_or(DEFAULT_TITLE_FULL);
+
+        validate("format", format, 20, &TITLE_PLACEHOLDERS)?;
+        validate("full text", full, 20, &TITLE_PLACEHOLDERS)?;
+
+        Ok(Self {
+            format: format.to_string(),
+            full: full.to_string(),
+        })
+    }
+
+    pub fn prefix(&self, post: &impl TemplateInfo) -> String {
+        let joined = post.fireteam().count();
+        self.render(post.open_slots() == 0, joined, post.fireteam_size())
+    }
+
+    /// The thread name for `base`, limited to Discord's 100 characters.
+    pub fn apply(&self, base: &str, post: &impl TemplateInfo) -> String {
+        format!("{} {}", self.prefix(post), base)
+            .chars()
+            .take(100)
+            .collect()
+    }
+
+    fn render(&self, full: bool, joined: usize, fireteam_size: i16) -> String {
+        let template = if full { &self.full } else { &self.format };
+
+        render(template, |name| match name {
+            "joined" => joined.to_string(),
+            "fireteam_size" => fireteam_size.to_string(),
+            "slots_left" => (fireteam_size.max(0) as usize)
+                .saturating_sub(joined)
+                .to_string(),
+            _ => String::new(),
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use serenity::all::{ChannelId, CreateEmbed, CreateEmbedFooter, Mentionable};
+
+use crate::locale::{message, message_with};
+
+use super::{Template, TemplateInfo, mentions, styled, summary};
+
+/// A single description block without fields, for busy channels.
+pub struct CompactTemplate;
+
+impl Template for CompactTemplate {
+    fn thread_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, None, locale)
+    }
+
+    fn message_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, Some(thread), locale)
+    }
+}
+
+fn embed(
+    post: &impl TemplateInfo,
+    owner_name: &str,
+    thread: Option<ChannelId>,
+    locale: &str,
+) -> CreateEmbed {
+    let timestamp = post.timestamp();
+
+    let fireteam = mentions(post.fireteam());
+    let alternatives = mentions(post.alternatives());
+
+    let title = match post.difficulty() {
+        Some(difficulty) => format!("{} ({})", post.activity(), difficulty),
+        None => post.activity().to_string(),
+    };
+
+    let mut lines = vec![format!(
+        "<t:{timestamp}:f> (<t:{timestamp}:R>) • {}/{} • {}",
+        fireteam.len(),
+        post.fireteam_size(),
+        summary(post, locale)
+    )];
+
+    if !fireteam.is_empty() {
+        lines.push(fireteam.join(" "));
+    }
+
+    if !alternatives.is_empty() {
+        lines.push(format!(
+            "{}: {}",
+            message(locale, "embed.alternatives"),
+            alternatives.join(" ")
+        ));
+    }
+
+    if !post.description().is_empty() && post.description() != post.activity() {
+        lines.push(format!("> {}", post.description().replace('\n', "\n> ")));
+    }
+
+    if let Some(thread) = thread {
+        lines.push(thread.mention().to_string());
+    }
+
+    let embed = CreateEmbed::new()
+        .title(title)
+        .description(lines.join("\n"))
+        .footer(CreateEmbedFooter::new(message_with(
+            locale,
+            "embed.posted_by",
+            &[("owner", &owner_name)],
+        )));
+
+    styled(embed, post)
+}
@@ -0,0 +1,109 @@
+use serenity::all::{ChannelId, CreateEmbed, CreateEmbedFooter, Mentionable};
+
+use crate::locale::{message, message_with};
+
+use super::{Template, TemplateInfo, mentions, roster, split_field, styled, summary};
+
+pub struct DefaultTemplate;
+
+impl Template for DefaultTemplate {
+    fn thread_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, None, locale)
+    }
+
+    fn message_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, Some(thread), locale)
+    }
+}
+
+fn embed(
+    post: &impl TemplateInfo,
+    owner_name: &str,
+    thread: Option<ChannelId>,
+    locale: &str,
+) -> CreateEmbed {
+    let timestamp = post.timestamp();
+
+    let joined = post.fireteam().count();
+    let alternatives = mentions(post.alternatives());
+
+    let title = format!("{} - <t:{}>", post.activity(), timestamp);
+
+    let mut embed = CreateEmbed::new()
+        .title(if post.started() {
+            format!("{} {title}", message(locale, "embed.started_title"))
+        } else {
+            title
+        })
+        .description(format!("**{}**", summary(post, locale)))
+        .field(message(locale, "embed.activity"), post.activity(), true)
+        .field(
+            message(locale, "embed.start_time"),
+            format!("<t:{}:R>", timestamp),
+            true,
+        );
+
+    if let Some(difficulty) = post.difficulty() {
+        embed = embed.field(
+            message(locale, "embed.difficulty"),
+            difficulty.to_string(),
+            true,
+        );
+    }
+
+    embed = styled(embed, post);
+
+    if let Some(thread) = thread {
+        embed = embed.field(
+            message(locale, "embed.event_thread"),
+            thread.mention().to_string(),
+            true,
+        );
+    }
+
+    if !post.description().is_empty() {
+        embed = embed.field(
+            message(locale, "embed.description"),
+            post.description(),
+            false,
+        )
+    }
+
+    embed = split_field(
+        embed,
+        message_with(
+            locale,
+            "embed.joined",
+            &[("joined", &joined), ("size", &post.fireteam_size())],
+        ),
+        &roster(post, locale),
+        false,
+    )
+    .footer(CreateEmbedFooter::new(message_with(
+        locale,
+        "embed.posted_by",
+        &[("owner", &owner_name)],
+    )));
+
+    if !alternatives.is_empty() {
+        embed = split_field(
+            embed,
+            message(locale, "embed.alternatives"),
+            &alternatives,
+            true,
+        );
+    }
+
+    embed
+}
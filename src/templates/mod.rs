@@ -0,0 +1,362 @@
+mod compact;
+mod default;
+mod pvp;
+mod raid;
+pub mod text;
+
+pub use compact::CompactTemplate;
+pub use default::DefaultTemplate;
+pub use pvp::PvpTemplate;
+pub use raid::RaidTemplate;
+pub use text::{GuildTemplateRow, TemplateManager, TextTemplate};
+
+use chrono::Utc;
+use serenity::all::{
+    ButtonStyle, ChannelId, Colour, CreateActionRow, CreateButton, CreateEmbed, GuildId,
+    Mentionable, MessageId, UserId,
+};
+use sqlx::{Database, Pool};
+
+use crate::locale::{message, message_with};
+use crate::{Activity, ActivityCatalog, ActivityCategory, Difficulty};
+
+/// The most open slots shown with a placeholder each, the rest are counted in one line.
+const OPEN_PLACEHOLDERS: usize = 3;
+/// Discord's limit on the length of an embed field's value.
+const FIELD_LIMIT: usize = 1024;
+
+pub trait TemplateInfo {
+    fn game(&self) -> &str;
+
+    fn activity(&self) -> &str;
+
+    fn difficulty(&self) -> Option<Difficulty>;
+
+    fn template(&self) -> TemplateKind;
+
+    fn timestamp(&self) -> i64;
+
+    fn description(&self) -> &str;
+
+    fn fireteam_size(&self) -> i16;
+
+    fn fireteam(&self) -> impl Iterator<Item = UserId>;
+
+    fn alternatives(&self) -> impl Iterator<Item = UserId>;
+
+    fn alt_channel(&self) -> Option<ChannelId>;
+
+    fn alt_message(&self) -> Option<MessageId>;
+
+    /// The guild of the post, if it's known.
+    fn guild_id(&self) -> Option<GuildId>;
+
+    /// The activity in the catalog or the guild's custom activities, if it's a known activity.
+    fn catalog_activity(&self) -> Option<Activity> {
+        ActivityCatalog::find_for(self.guild_id(), self.game(), self.activity())
+    }
+
+    fn category(&self) -> Option<ActivityCategory> {
+        self.catalog_activity().map(|activity| activity.category)
+    }
+
+    /// The activity's own colour, falling back to its category's. Greyed out once started.
+    fn colour(&self) -> Option<Colour> {
+        if self.started() {
+            return Some(Colour::DARK_GREY);
+        }
+
+        let activity = self.catalog_activity()?;
+        Some(
+            activity
+                .colour
+                .unwrap_or_else(|| activity.category.colour()),
+        )
+    }
+
+    /// The activity artwork.
+    fn thumbnail(&self) -> Option<String> {
+        self.catalog_activity()?
+            .thumbnail
+            .map(|thumbnail| thumbnail.into_owned())
+    }
+
+    fn open_slots(&self) -> usize {
+        (self.fireteam_size().max(0) as usize).saturating_sub(self.fireteam().count())
+    }
+
+    fn started(&self) -> bool {
+        self.timestamp() <= Utc::now().timestamp()
+    }
+}
+
+/// Renders a post's embeds, with the labels in `locale`.
+pub trait Template {
+    fn thread_embed(&self, post: &impl TemplateInfo, owner_name: &str, locale: &str)
+    -> CreateEmbed;
+
+    fn message_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed;
+
+    fn main_row() -> CreateActionRow {
+        CreateActionRow::Buttons(vec![
+            CreateButton::new("lfg_join")
+                .emoji('➕')
+                .style(ButtonStyle::Success),
+            CreateButton::new("lfg_leave")
+                .emoji('➖')
+                .style(ButtonStyle::Danger),
+            CreateButton::new("lfg_alternative")
+                .emoji('❔')
+                .style(ButtonStyle::Secondary),
+            CreateButton::new("lfg_calendar")
+                .emoji('📅')
+                .style(ButtonStyle::Secondary),
+            CreateButton::new("lfg_settings")
+                .emoji('⚙')
+                .style(ButtonStyle::Secondary),
+        ])
+    }
+
+    /// The buttons of a message mirroring the post outside its thread. Their custom ids end with
+    /// the thread, as the message isn't in it.
+    fn mirror_row(thread: ChannelId, label: Option<&str>) -> CreateActionRow {
+        let mut join = CreateButton::new(format!("lfg_join_{thread}"))
+            .emoji('➕')
+            .style(ButtonStyle::Success);
+        if let Some(label) = label {
+            join = join.label(label);
+        }
+
+        CreateActionRow::Buttons(vec![
+            join,
+            CreateButton::new(format!("lfg_leave_{thread}"))
+                .emoji('➖')
+                .style(ButtonStyle::Danger),
+            CreateButton::new(format!("lfg_alternative_{thread}"))
+                .emoji('❔')
+                .style(ButtonStyle::Secondary),
+            CreateButton::new(format!("lfg_calendar_{thread}"))
+                .emoji('📅')
+                .style(ButtonStyle::Secondary),
+        ])
+    }
+
+    fn settings_row(locale: &str) -> CreateActionRow {
+        CreateActionRow::Buttons(vec![
+            CreateButton::new("lfg_edit")
+                .label(message(locale, "button.edit"))
+                .style(ButtonStyle::Secondary),
+            CreateButton::new("lfg_copy")
+                .label(message(locale, "button.copy"))
+                .style(ButtonStyle::Secondary),
+            CreateButton::new("lfg_kick")
+                .label(message(locale, "button.kick"))
+                .style(ButtonStyle::Secondary),
+            CreateButton::new("lfg_delete")
+                .label(message(locale, "button.delete"))
+                .style(ButtonStyle::Danger),
+        ])
+    }
+}
+
+/// The registry of templates a post can be rendered with, stored on the post by id.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TemplateKind {
+    #[default]
+    Default,
+    Compact,
+    Raid,
+    Pvp,
+    /// The guild's text template, see [`TextTemplate`]
+    Guild,
+}
+
+impl TemplateKind {
+    pub const ALL: [TemplateKind; 5] = [
+        TemplateKind::Default,
+        TemplateKind::Compact,
+        TemplateKind::Raid,
+        TemplateKind::Pvp,
+        TemplateKind::Guild,
+    ];
+
+    pub fn id(&self) -> i16 {
+        match self {
+            TemplateKind::Default => 0,
+            TemplateKind::Compact => 1,
+            TemplateKind::Raid => 2,
+            TemplateKind::Pvp => 3,
+            TemplateKind::Guild => 4,
+        }
+    }
+
+    /// The template with the id, falling back to the default template for unknown ids.
+    pub fn from_id(id: i16) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|template| template.id() == id)
+            .unwrap_or_default()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TemplateKind::Default => "Default",
+            TemplateKind::Compact => "Compact",
+            TemplateKind::Raid => "Raid Roster",
+            TemplateKind::Pvp => "PvP Teams",
+            TemplateKind::Guild => "Server",
+        }
+    }
+
+    /// Renders the thread embed. `guild` is the guild's text template, without one the guild
+    /// template falls back to the default template.
+    pub fn thread_embed(
+        &self,
+        guild: Option<&TextTemplate>,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        locale: &str,
+    ) -> CreateEmbed {
+        match (self, guild) {
+            (TemplateKind::Compact, _) => CompactTemplate.thread_embed(post, owner_name, locale),
+            (TemplateKind::Raid, _) => RaidTemplate.thread_embed(post, owner_name, locale),
+            (TemplateKind::Pvp, _) => PvpTemplate.thread_embed(post, owner_name, locale),
+            (TemplateKind::Guild, Some(template)) => {
+                template.thread_embed(post, owner_name, locale)
+            }
+            _ => DefaultTemplate.thread_embed(post, owner_name, locale),
+        }
+    }
+
+    pub fn message_embed(
+        &self,
+        guild: Option<&TextTemplate>,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed {
+        match (self, guild) {
+            (TemplateKind::Compact, _) => {
+                CompactTemplate.message_embed(post, owner_name, thread, locale)
+            }
+            (TemplateKind::Raid, _) => RaidTemplate.message_embed(post, owner_name, thread, locale),
+            (TemplateKind::Pvp, _) => PvpTemplate.message_embed(post, owner_name, thread, locale),
+            (TemplateKind::Guild, Some(template)) => {
+                template.message_embed(post, owner_name, thread, locale)
+            }
+            _ => DefaultTemplate.message_embed(post, owner_name, thread, locale),
+        }
+    }
+
+    pub fn main_row(&self) -> CreateActionRow {
+        match self {
+            TemplateKind::Default | TemplateKind::Guild => DefaultTemplate::main_row(),
+            TemplateKind::Compact => CompactTemplate::main_row(),
+            TemplateKind::Raid => RaidTemplate::main_row(),
+            TemplateKind::Pvp => PvpTemplate::main_row(),
+        }
+    }
+
+    /// The guild's text template, if the post uses it.
+    pub async fn guild_template<Db: Database, Manager: TemplateManager<Db>>(
+        &self,
+        pool: &Pool<Db>,
+        guild_id: GuildId,
+    ) -> sqlx::Result<Option<TextTemplate>> {
+        if *self != TemplateKind::Guild {
+            return Ok(None);
+        }
+
+        let row = Manager::guild_template(pool, guild_id).await?;
+
+        Ok(row.and_then(|row| TextTemplate::try_from(row).ok()))
+    }
+}
+
+/// The embed with the post's thumbnail and colour, if it has any.
+fn styled(embed: CreateEmbed, post: &impl TemplateInfo) -> CreateEmbed {
+    let mut embed = embed;
+
+    if let Some(thumbnail) = post.thumbnail() {
+        embed = embed.thumbnail(thumbnail);
+    }
+
+    if let Some(colour) = post.colour() {
+        embed = embed.colour(colour);
+    }
+
+    embed
+}
+
+/// A one line summary of how many players the post still needs.
+fn summary(post: &impl TemplateInfo, locale: &str) -> String {
+    if post.started() {
+        let time = format!("<t:{}:R>", post.timestamp());
+        return message_with(locale, "embed.started", &[("time", &time)]);
+    }
+
+    match post.open_slots() {
+        0 => message(locale, "embed.full"),
+        n => message_with(locale, "embed.looking_for", &[("count", &n)]),
+    }
+}
+
+/// The fireteam, padded with a placeholder for each open slot up to [`OPEN_PLACEHOLDERS`], and a
+/// line counting the rest.
+fn roster(post: &impl TemplateInfo, locale: &str) -> Vec<String> {
+    let open = message(locale, "embed.open");
+    let open_slots = post.open_slots();
+
+    let mut roster = mentions(post.fireteam());
+    roster.extend((0..open_slots.min(OPEN_PLACEHOLDERS)).map(|_| open.clone()));
+
+    if open_slots > OPEN_PLACEHOLDERS {
+        roster.push(more_open(open_slots - OPEN_PLACEHOLDERS, locale));
+    }
+
+    roster
+}
+
+fn more_open(count: usize, locale: &str) -> String {
+    message_with(locale, "embed.more_open", &[("count", &count)])
+}
+
+/// Adds the lines as a field, continued in fields without a name when they don't fit in one.
+fn split_field(
+    mut embed: CreateEmbed,
+    name: impl Into<String>,
+    lines: &[String],
+    inline: bool,
+) -> CreateEmbed {
+    let mut name = name.into();
+    let mut value = String::new();
+
+    for line in lines {
+        if !value.is_empty() && value.len() + 1 + line.len() > FIELD_LIMIT {
+            embed = embed.field(
+                std::mem::replace(&mut name, String::from("\u{200b}")),
+                value,
+                inline,
+            );
+            value = String::new();
+        }
+
+        if !value.is_empty() {
+            value.push('\n');
+        }
+        value.push_str(line);
+    }
+
+    embed.field(name, value, inline)
+}
+
+fn mentions(users: impl Iterator<Item = UserId>) -> Vec<String> {
+    users.map(|id| id.mention().to_string()).collect()
+}
@@ -0,0 +1,120 @@
+use serenity::all::{ChannelId, CreateEmbed, CreateEmbedFooter, Mentionable};
+
+use crate::locale::{message, message_with};
+
+use super::{OPEN_PLACEHOLDERS, Template, TemplateInfo, mentions, more_open, split_field, styled};
+
+/// Splits the fireteam into two teams in join order, e.g. for private matches.
+pub struct PvpTemplate;
+
+impl Template for PvpTemplate {
+    fn thread_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, None, locale)
+    }
+
+    fn message_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, Some(thread), locale)
+    }
+}
+
+fn embed(
+    post: &impl TemplateInfo,
+    owner_name: &str,
+    thread: Option<ChannelId>,
+    locale: &str,
+) -> CreateEmbed {
+    let timestamp = post.timestamp();
+
+    let fireteam = mentions(post.fireteam());
+    let alternatives = mentions(post.alternatives());
+
+    // Alpha takes the extra slot of an odd fireteam
+    let size = post.fireteam_size().max(2) as usize;
+    let alpha_size = size.div_ceil(2);
+    let bravo_size = size - alpha_size;
+    let (alpha, bravo) = fireteam.split_at(fireteam.len().min(alpha_size));
+
+    let open = message(locale, "embed.open");
+
+    let team = |members: &[String], team_size: usize| {
+        let open_slots = team_size.saturating_sub(members.len());
+
+        let mut slots = members.to_vec();
+        slots.extend((0..open_slots.min(OPEN_PLACEHOLDERS)).map(|_| open.clone()));
+
+        if open_slots > OPEN_PLACEHOLDERS {
+            slots.push(more_open(open_slots - OPEN_PLACEHOLDERS, locale));
+        }
+
+        slots
+    };
+
+    let mut embed = CreateEmbed::new()
+        .title(format!("{} - <t:{}>", post.activity(), timestamp))
+        .field(
+            message(locale, "embed.start_time"),
+            format!("<t:{}:R>", timestamp),
+            true,
+        )
+        .field(
+            message(locale, "embed.players"),
+            format!("{}/{}", fireteam.len(), post.fireteam_size()),
+            true,
+        );
+
+    if let Some(thread) = thread {
+        embed = embed.field(
+            message(locale, "embed.event_thread"),
+            thread.mention().to_string(),
+            true,
+        );
+    }
+
+    if !post.description().is_empty() {
+        embed = embed.field(
+            message(locale, "embed.description"),
+            post.description(),
+            false,
+        )
+    }
+
+    embed = split_field(
+        embed,
+        message(locale, "embed.team_alpha"),
+        &team(alpha, alpha_size),
+        true,
+    );
+    embed = split_field(
+        embed,
+        message(locale, "embed.team_bravo"),
+        &team(bravo, bravo_size),
+        true,
+    )
+    .footer(CreateEmbedFooter::new(message_with(
+        locale,
+        "embed.posted_by",
+        &[("owner", &owner_name)],
+    )));
+
+    if !alternatives.is_empty() {
+        embed = split_field(
+            embed,
+            message(locale, "embed.substitutes"),
+            &alternatives,
+            false,
+        );
+    }
+
+    styled(embed, post)
+}
@@ -0,0 +1,122 @@
+use std::iter;
+
+use serenity::all::{ChannelId, CreateEmbed, CreateEmbedFooter, Mentionable};
+
+use crate::locale::{message, message_with};
+
+use super::{OPEN_PLACEHOLDERS, Template, TemplateInfo, mentions, more_open, split_field, styled};
+
+/// A detailed roster with a numbered slot for every member of the fireteam.
+pub struct RaidTemplate;
+
+impl Template for RaidTemplate {
+    fn thread_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, None, locale)
+    }
+
+    fn message_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, Some(thread), locale)
+    }
+}
+
+fn embed(
+    post: &impl TemplateInfo,
+    owner_name: &str,
+    thread: Option<ChannelId>,
+    locale: &str,
+) -> CreateEmbed {
+    let timestamp = post.timestamp();
+
+    let fireteam = mentions(post.fireteam());
+    let alternatives = mentions(post.alternatives());
+
+    let open = message(locale, "embed.open");
+    let open_slots = post.open_slots();
+
+    let mut roster = fireteam
+        .iter()
+        .chain(iter::repeat_n(&open, open_slots.min(OPEN_PLACEHOLDERS)))
+        .enumerate()
+        .map(|(i, member)| format!("`{:>2}.` {}", i + 1, member))
+        .collect::<Vec<_>>();
+
+    if open_slots > OPEN_PLACEHOLDERS {
+        roster.push(more_open(open_slots - OPEN_PLACEHOLDERS, locale));
+    }
+
+    let mut embed = CreateEmbed::new()
+        .title(format!("{} - <t:{}>", post.activity(), timestamp))
+        .field(
+            message(locale, "embed.start_time"),
+            format!("<t:{timestamp}:F>\n<t:{timestamp}:R>"),
+            true,
+        )
+        .field(
+            message(locale, "embed.difficulty"),
+            post.difficulty()
+                .map(|difficulty| difficulty.to_string())
+                .unwrap_or_else(|| message(locale, "embed.normal")),
+            true,
+        );
+
+    if let Some(thread) = thread {
+        embed = embed.field(
+            message(locale, "embed.event_thread"),
+            thread.mention().to_string(),
+            true,
+        );
+    }
+
+    if !post.description().is_empty() {
+        embed = embed.field(
+            message(locale, "embed.description"),
+            post.description(),
+            false,
+        )
+    }
+
+    embed = split_field(
+        embed,
+        message_with(
+            locale,
+            "embed.roster",
+            &[("joined", &fireteam.len()), ("size", &post.fireteam_size())],
+        ),
+        &roster,
+        false,
+    );
+
+    if !alternatives.is_empty() {
+        let alternatives = alternatives
+            .iter()
+            .enumerate()
+            .map(|(i, member)| format!("`{:>2}.` {}", i + 1, member))
+            .collect::<Vec<_>>();
+
+        embed = split_field(
+            embed,
+            message(locale, "embed.alternatives"),
+            &alternatives,
+            false,
+        );
+    }
+
+    embed = embed.footer(CreateEmbedFooter::new(message_with(
+        locale,
+        "embed.raid_lead",
+        &[("owner", &owner_name)],
+    )));
+
+    styled(embed, post)
+}
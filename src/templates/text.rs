@@ -0,0 +1,359 @@
+use async_trait::async_trait;
+use serenity::all::{ChannelId, Colour, CreateEmbed, CreateEmbedFooter, GuildId, Mentionable};
+use sqlx::prelude::FromRow;
+use sqlx::{Database, Pool, any::AnyQueryResult};
+
+use crate::locale::message;
+use crate::{Error, Result};
+
+use super::{Template, TemplateInfo, mentions};
+
+/// The placeholders available in a text template.
+pub const PLACEHOLDERS: [&str; 11] = [
+    "activity",
+    "difficulty",
+    "description",
+    "start",
+    "start_relative",
+    "owner",
+    "fireteam",
+    "alternatives",
+    "joined",
+    "fireteam_size",
+    "slots_left",
+];
+
+/// The placeholders that render empty for some posts.
+const OPTIONAL_PLACEHOLDERS: [&str; 2] = ["difficulty", "description"];
+
+#[async_trait]
+pub trait TemplateManager<Db: Database> {
+    async fn guild_template(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<GuildTemplateRow>>;
+
+    async fn save_guild_template(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        row: GuildTemplateRow,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn delete_guild_template(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+#[derive(FromRow)]
+pub struct GuildTemplateRow {
+    pub title: String,
+    pub description: String,
+    pub colour: Option<i32>,
+    pub thumbnail: Option<String>,
+    pub footer: Option<String>,
+}
+
+impl From<&TextTemplate> for GuildTemplateRow {
+    fn from(value: &TextTemplate) -> Self {
+        Self {
+            title: value.title.clone(),
+            description: value.description.clone(),
+            colour: value.colour.map(|colour| colour.0 as i32),
+            thumbnail: value.thumbnail.clone(),
+            footer: value.footer.clone(),
+        }
+    }
+}
+
+/// A guild's own embed template, written as text with `{placeholder}`s.
+///
+/// Use `{{` and `}}` for literal braces.
+#[derive(Debug, Clone)]
+pub struct TextTemplate {
+    pub title: String,
+    pub description: String,
+    pub colour: Option<Colour>,
+    pub thumbnail: Option<String>,
+    pub footer: Option<String>,
+}
+
+impl TextTemplate {
+    /// Validates the template, with `colour` as a hex code such as `#2E86C1`.
+    pub fn new(
+        title: &str,
+        description: &str,
+        colour: Option<&str>,
+        thumbnail: Option<&str>,
+        footer: Option<&str>,
+    ) -> Result<Self> {
+        let title = title.trim();
+        let description = description.trim();
+
+        if title.is_empty() {
+            return Err(Error::InvalidTemplate(String::from(
+                "The title can't be empty",
+            )));
+        }
+
+        validate("title", title, 256, &PLACEHOLDERS)?;
+
+        let rendered = render(title, |name| {
+            if OPTIONAL_PLACEHOLDERS.contains(&name) {
+                String::new()
+            } else {
+                name.to_string()
+            }
+        });
+        if rendered.trim().is_empty() {
+            return Err(Error::InvalidTemplate(String::from(
+                "The title can't be only {difficulty} or {description}, as they can be empty",
+            )));
+        }
+        validate("description", description, 4096, &PLACEHOLDERS)?;
+
+        let footer = non_empty(footer);
+        if let Some(footer) = footer {
+            validate("footer", footer, 2048, &PLACEHOLDERS)?;
+        }
+
+        let colour = match non_empty(colour) {
+            Some(colour) => {
+                let hex = colour.trim_start_matches('#');
+
+                let value = u32::from_str_radix(hex, 16)
+                    .ok()
+                    .filter(|value| hex.len() == 6 && *value <= 0xFFFFFF)
+                    .ok_or_else(|| {
+                        Error::InvalidTemplate(format!(
+                            "'{colour}' is not a hex colour like #2E86C1"
+                        ))
+                    })?;
+
+                Some(Colour::new(value))
+            }
+            None => None,
+        };
+
+        let thumbnail = non_empty(thumbnail);
+        if let Some(thumbnail) = thumbnail
+            && !(thumbnail.starts_with("https://") || thumbnail.starts_with("http://"))
+        {
+            return Err(Error::InvalidTemplate(String::from(
+                "The thumbnail must be a http(s) link to an image",
+            )));
+        }
+
+        Ok(Self {
+            title: title.to_string(),
+            description: description.to_string(),
+            colour,
+            thumbnail: thumbnail.map(String::from),
+            footer: footer.map(String::from),
+        })
+    }
+
+    fn embed(&self, post: &impl TemplateInfo, owner_name: &str) -> CreateEmbed {
+        let render = |template: &str| render(template, |name| value(name, post, owner_name));
+
+        // Discord rejects embeds with an empty title
+        let mut title = render(&self.title);
+        if title.trim().is_empty() {
+            title = post.activity().to_string();
+        }
+
+        let mut embed = CreateEmbed::new().title(truncate(title, 256));
+
+        let description = render(&self.description);
+        if !description.is_empty() {
+            embed = embed.description(truncate(description, 4096));
+        }
+
+        if let Some(colour) = self.colour {
+            embed = embed.colour(colour);
+        }
+
+        if let Some(thumbnail) = &self.thumbnail {
+            embed = embed.thumbnail(thumbnail);
+        }
+
+        if let Some(footer) = &self.footer {
+            embed = embed.footer(CreateEmbedFooter::new(truncate(render(footer), 2048)));
+        }
+
+        embed
+    }
+}
+
+impl TryFrom<GuildTemplateRow> for TextTemplate {
+    type Error = Error;
+
+    fn try_from(value: GuildTemplateRow) -> Result<Self> {
+        let colour = value.colour.map(|colour| format!("{:06X}", colour));
+
+        Self::new(
+            &value.title,
+            &value.description,
+            colour.as_deref(),
+            value.thumbnail.as_deref(),
+            value.footer.as_deref(),
+        )
+    }
+}
+
+impl Template for TextTemplate {
+    fn thread_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        _locale: &str,
+    ) -> CreateEmbed {
+        self.embed(post, owner_name)
+    }
+
+    fn message_embed(
+        &self,
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed {
+        self.embed(post, owner_name).field(
+            message(locale, "embed.event_thread"),
+            thread.mention().to_string(),
+            true,
+        )
+    }
+}
+
+fn value(name: &str, post: &impl TemplateInfo, owner_name: &str) -> String {
+    let timestamp = post.timestamp();
+    let joined = post.fireteam().count();
+
+    let list = |users: Vec<String>| {
+        if users.is_empty() {
+            String::from("-")
+        } else {
+            users.join("\n")
+        }
+    };
+
+    match name {
+        "activity" => post.activity().to_string(),
+        "difficulty" => post
+            .difficulty()
+            .map(|difficulty| difficulty.to_string())
+            .unwrap_or_default(),
+        "description" => post.description().to_string(),
+        "start" => format!("<t:{timestamp}:F>"),
+        "start_relative" => format!("<t:{timestamp}:R>"),
+        "owner" => owner_name.to_string(),
+        "fireteam" => list(mentions(post.fireteam())),
+        "alternatives" => list(mentions(post.alternatives())),
+        "joined" => joined.to_string(),
+        "fireteam_size" => post.fireteam_size().to_string(),
+        "slots_left" => post.open_slots().to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Checks the placeholders and length of a template field.
+pub(crate) fn validate(
+    field: &str,
+    template: &str,
+    max_len: usize,
+    placeholders: &[&str],
+) -> Result<()> {
+    if template.chars().count() > max_len {
+        return Err(Error::InvalidTemplate(format!(
+            "The {field} can be at most {max_len} characters"
+        )));
+    }
+
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '{' => {
+                let mut name = String::new();
+                let mut closed = false;
+
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(c);
+                }
+
+                if !closed {
+                    return Err(Error::InvalidTemplate(format!(
+                        "Unclosed '{{' in the {field}. Use '{{{{' for a literal brace"
+                    )));
+                }
+
+                if !placeholders.contains(&name.trim()) {
+                    return Err(Error::InvalidTemplate(format!(
+                        "Unknown placeholder '{{{name}}}' in the {field}. Available placeholders: {}",
+                        placeholders
+                            .iter()
+                            .map(|p| format!("{{{p}}}"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )));
+                }
+            }
+            '}' => {
+                return Err(Error::InvalidTemplate(format!(
+                    "Unmatched '}}' in the {field}. Use '}}}}' for a literal brace"
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn render(template: &str, value: impl Fn(&str) -> String) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                output.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                output.push('}');
+            }
+            '{' => {
+                let name = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+                output.push_str(&value(name.trim()));
+            }
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+fn non_empty(s: Option<&str>) -> Option<&str> {
+    s.map(str::trim).filter(|s| !s.is_empty())
+}
+
+fn truncate(s: String, max_len: usize) -> String {
+    if s.chars().count() <= max_len {
+        return s;
+    }
+
+    s.chars().take(max_len - 1).chain(['…']).collect()
+}
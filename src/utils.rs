@@ -1,33 +1,249 @@
-use std::fmt::Display;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
 
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use chrono_tz::Tz;
 use serenity::all::{
-    ChannelId, Context, CreateMessage, DiscordJsonError, EditMessage, ErrorResponse, HttpError,
-    Mentionable, UserId,
+    ChannelId, ComponentInteraction, Context, CreateMessage, DiscordJsonError, EditMessage,
+    EditThread, ErrorResponse, GuildChannel, GuildId, HttpError, Member, Mentionable, UserId,
 };
+use sqlx::{Database, Pool};
+use zayden_core::{CronJob, cron::CronJobs};
 
-use crate::templates::{Template, TemplateInfo};
+use crate::locale::{LocaleManager, guild_locale, message_with};
+use crate::models::announcement::FEED_LINES;
+use crate::models::schedule::{BOARD_DAYS, board};
+use crate::models::tag_mapping::with_status_tags;
+use crate::models::thread_title::{RENAME_LIMIT, RENAME_WINDOW_SECS};
+use crate::models::timezone_manager::LOCALE_TO_TIMEZONE;
+use crate::models::{AnnouncementManager, AnnouncementMode, ScheduleManager};
+use crate::templates::{DefaultTemplate, Template, TemplateInfo, TemplateKind, TemplateManager};
+use crate::{Error, PostManager, PostRow, Result, TagMappingManager, ThreadTitleManager};
 
-pub async fn update_embeds<T: Template>(
+/// The thread of a `thread` option, given as an id or a channel mention.
+pub(crate) fn parse_thread(value: &str) -> Option<ChannelId> {
+    let value = value.trim();
+    let id = value
+        .strip_prefix("<#")
+        .and_then(|value| value.strip_suffix('>'))
+        .unwrap_or(value);
+
+    id.parse::<u64>()
+        .ok()
+        .filter(|&id| id != 0)
+        .map(ChannelId::new)
+}
+
+/// The thread a button is for. Buttons on mirror messages end their custom id with it, others
+/// are in the thread.
+pub(crate) fn component_thread(interaction: &ComponentInteraction) -> ChannelId {
+    interaction
+        .data
+        .custom_id
+        .rsplit_once('_')
+        .and_then(|(_, id)| parse_thread(id))
+        .unwrap_or(interaction.channel_id)
+}
+
+/// Checks the post is in the guild the interaction came from, as a `thread` option can name a
+/// post of any guild.
+///
+/// Posts stored before their guild was are checked against their thread's guild.
+pub(crate) async fn same_guild(
     ctx: &Context,
+    row: &PostRow,
+    thread: ChannelId,
+    guild: Option<GuildId>,
+) -> Result<()> {
+    let post_guild = match row.guild() {
+        Some(guild) => Some(guild),
+        None => thread
+            .to_channel(ctx)
+            .await?
+            .guild()
+            .map(|channel| channel.guild_id),
+    };
+
+    if post_guild.is_none() || post_guild != guild {
+        return Err(Error::ChannelNotFound);
+    }
+
+    Ok(())
+}
+
+/// Whether the member can moderate other members' posts.
+pub(crate) fn is_moderator(member: Option<&Member>) -> bool {
+    member
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.manage_guild() || permissions.manage_threads())
+}
+
+/// Checks the user can manage the post, returning whether a moderator is overriding the owner.
+///
+/// Besides the owner, members with Manage Threads or Manage Server can edit, delete, tag, kick
+/// from and change the settings of any post, not only their own.
+pub(crate) fn owner_or_moderator(
+    owner: UserId,
+    user: UserId,
+    member: Option<&Member>,
+) -> Result<bool> {
+    if user == owner {
+        return Ok(false);
+    }
+
+    if is_moderator(member) {
+        return Ok(true);
+    }
+
+    Err(Error::PermissionDenied(owner))
+}
+
+/// Embed edits of a post closer together than this are coalesced into one.
+const EMBED_DEBOUNCE_SECS: i64 = 2;
+
+static EMBED_UPDATES: LazyLock<Mutex<HashMap<ChannelId, EmbedUpdates>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+struct EmbedUpdates {
+    last: DateTime<Utc>,
+    /// The owner name and locale of the latest change, for the scheduled update
+    scheduled: Option<(String, String)>,
+}
+
+/// Re-renders the post's embeds with the post's own template.
+///
+/// The first change to a post is shown right away. Changes within [`EMBED_DEBOUNCE_SECS`] of
+/// the last edit are batched into one scheduled edit, which renders the post as it is then.
+pub async fn update_embeds<Db: Database, Manager: PostManager<Db> + TemplateManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
     row: &impl TemplateInfo,
     owner_name: &str,
     thread: impl Into<ChannelId>,
-) {
+    locale: &str,
+) -> Result<()> {
     let thread = thread.into();
+    let now = Utc::now();
+
+    let update_at = {
+        let mut updates = EMBED_UPDATES.lock().unwrap();
 
-    let embed = T::thread_embed(row, owner_name);
+        updates.retain(|_, updates| {
+            updates.scheduled.is_some()
+                || now - updates.last < Duration::seconds(EMBED_DEBOUNCE_SECS)
+        });
+
+        match updates.get_mut(&thread) {
+            Some(updates) if updates.scheduled.is_some() => {
+                updates.scheduled = Some((owner_name.to_string(), locale.to_string()));
+                return Ok(());
+            }
+            Some(updates) => {
+                updates.scheduled = Some((owner_name.to_string(), locale.to_string()));
+                Some(updates.last + Duration::seconds(EMBED_DEBOUNCE_SECS))
+            }
+            None => {
+                updates.insert(
+                    thread,
+                    EmbedUpdates {
+                        last: now,
+                        scheduled: None,
+                    },
+                );
+                None
+            }
+        }
+    };
+
+    let Some(update_at) = update_at else {
+        return render_embeds::<Db, Manager>(ctx, pool, row, owner_name, thread, locale).await;
+    };
+
+    // Cron jobs run on whole seconds
+    let update_at = update_at + Duration::seconds(1);
+
+    let job = CronJob::<Db>::new(&format!(
+        "{} {} {} {} {} * {}",
+        update_at.second(),
+        update_at.minute(),
+        update_at.hour(),
+        update_at.day(),
+        update_at.month(),
+        update_at.year()
+    ))
+    .set_action(move |ctx, pool| async move {
+        let Some((owner_name, locale)) =
+            EMBED_UPDATES
+                .lock()
+                .unwrap()
+                .get_mut(&thread)
+                .and_then(|updates| {
+                    updates.last = Utc::now();
+                    updates.scheduled.take()
+                })
+        else {
+            return;
+        };
+
+        let post = match Manager::row(&pool, thread).await {
+            Ok(post) => post,
+            Err(sqlx::Error::RowNotFound) => return,
+            Err(e) => {
+                eprintln!("Failed to load the post for '{thread}': {e:?}");
+                return;
+            }
+        };
+
+        if let Err(e) =
+            render_embeds::<Db, Manager>(&ctx, &pool, &post, &owner_name, thread, &locale).await
+        {
+            eprintln!("Failed to update the embeds of '{thread}': {e}");
+        }
+    });
+
+    let mut data = ctx.data.write().await;
+    data.entry::<CronJobs<Db>>().or_insert(Vec::new()).push(job);
+
+    Ok(())
+}
+
+async fn render_embeds<Db: Database, Manager: TemplateManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    row: &impl TemplateInfo,
+    owner_name: &str,
+    thread: ChannelId,
+    locale: &str,
+) -> Result<()> {
+    let template = row.template();
+
+    let guild_template = match template {
+        TemplateKind::Guild => match thread.to_channel(ctx).await?.guild() {
+            Some(channel) => {
+                template
+                    .guild_template::<Db, Manager>(pool, channel.guild_id)
+                    .await?
+            }
+            None => None,
+        },
+        _ => None,
+    };
+
+    let embed = template.thread_embed(guild_template.as_ref(), row, owner_name, locale);
 
     thread
         .edit_message(ctx, thread.get(), EditMessage::new().embed(embed))
-        .await
-        .unwrap();
+        .await?;
 
     if let (Some(channel), Some(message)) = (row.alt_channel(), row.alt_message()) {
-        let embed = T::message_embed(row, owner_name, thread);
+        let embed =
+            template.message_embed(guild_template.as_ref(), row, owner_name, thread, locale);
 
-        match channel
-            .edit_message(ctx, message, EditMessage::new().embed(embed))
-            .await
+        let message_edit = EditMessage::new()
+            .embed(embed)
+            .components(vec![DefaultTemplate::mirror_row(thread, None)]);
+
+        match channel.edit_message(ctx, message, message_edit).await
         {
             Ok(_)
             // Unknown Message
@@ -35,35 +251,537 @@ pub async fn update_embeds<T: Template>(
                 error: DiscordJsonError { code: 10008, .. },
                 ..
             }))) => {}
-            Err(e) => panic!("{e:?}"),
+            Err(e) => return Err(e.into()),
         };
     }
+
+    Ok(())
+}
+
+/// Updates the status tags of the thread, only editing it if they changed.
+pub async fn sync_status_tags<Db: Database, Manager: TagMappingManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    post: &impl TemplateInfo,
+    thread: impl Into<ChannelId>,
+) -> Result<()> {
+    let Some(mut channel) = thread.into().to_channel(ctx).await?.guild() else {
+        return Ok(());
+    };
+
+    let rows = Manager::status_tags(pool, channel.guild_id).await?;
+
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let tags = with_status_tags(&channel.applied_tags, &rows, post, Utc::now());
+
+    let unchanged = tags.len() == channel.applied_tags.len()
+        && tags.iter().all(|id| channel.applied_tags.contains(id));
+
+    if unchanged {
+        return Ok(());
+    }
+
+    channel
+        .edit_thread(ctx, EditThread::new().applied_tags(tags))
+        .await?;
+
+    Ok(())
+}
+
+static RENAMES: LazyLock<Mutex<HashMap<ChannelId, Renames>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Default)]
+struct Renames {
+    recent: Vec<DateTime<Utc>>,
+    scheduled: bool,
+}
+
+/// Keeps the guild's slot count prefix on the thread name in sync, renaming it to `base` when
+/// set.
+///
+/// The name is always rebuilt from the thread's stored base rather than its current name, so a
+/// prefix never ends up in the base.
+///
+/// Renames past Discord's limit are coalesced into one rename once the limit resets, which uses
+/// the post as it is then.
+pub async fn sync_thread_title<Db: Database, Manager: PostManager<Db> + ThreadTitleManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    post: &impl TemplateInfo,
+    thread: impl Into<ChannelId>,
+    base: Option<String>,
+) -> Result<()> {
+    let thread = thread.into();
+
+    if let Some(base) = base {
+        Manager::save_thread_base(pool, thread, base).await?;
+    }
+
+    if RENAMES
+        .lock()
+        .unwrap()
+        .get(&thread)
+        .is_some_and(|renames| renames.scheduled)
+    {
+        return Ok(());
+    }
+
+    let Some((mut channel, name)) = thread_name::<Db, Manager>(ctx, pool, post, thread).await?
+    else {
+        return Ok(());
+    };
+
+    let now = Utc::now();
+
+    let retry_at = {
+        let mut renames = RENAMES.lock().unwrap();
+        let renames = renames.entry(thread).or_default();
+
+        renames
+            .recent
+            .retain(|time| now - *time < Duration::seconds(RENAME_WINDOW_SECS));
+
+        if renames.recent.len() < RENAME_LIMIT {
+            renames.recent.push(now);
+            None
+        } else {
+            renames.scheduled = true;
+            renames
+                .recent
+                .iter()
+                .min()
+                .map(|oldest| *oldest + Duration::seconds(RENAME_WINDOW_SECS + 1))
+        }
+    };
+
+    let Some(retry_at) = retry_at else {
+        channel
+            .edit_thread(ctx, EditThread::new().name(name))
+            .await?;
+        return Ok(());
+    };
+
+    let job = CronJob::<Db>::new(&format!(
+        "{} {} {} {} {} * {}",
+        retry_at.second(),
+        retry_at.minute(),
+        retry_at.hour(),
+        retry_at.day(),
+        retry_at.month(),
+        retry_at.year()
+    ))
+    .set_action(move |ctx, pool| async move {
+        RENAMES.lock().unwrap().entry(thread).or_default().scheduled = false;
+
+        let post = match Manager::row(&pool, thread).await {
+            Ok(post) => post,
+            Err(sqlx::Error::RowNotFound) => return,
+            Err(e) => {
+                eprintln!("Failed to load the post for '{thread}': {e:?}");
+                return;
+            }
+        };
+
+        let (mut channel, name) = match thread_name::<Db, Manager>(&ctx, &pool, &post, thread).await
+        {
+            Ok(Some(rename)) => rename,
+            Ok(None) => return,
+            Err(e) => {
+                eprintln!("Failed to rename '{thread}': {e}");
+                return;
+            }
+        };
+
+        RENAMES
+            .lock()
+            .unwrap()
+            .entry(thread)
+            .or_default()
+            .recent
+            .push(Utc::now());
+
+        if let Err(e) = channel
+            .edit_thread(&ctx, EditThread::new().name(name))
+            .await
+        {
+            eprintln!("Failed to rename '{thread}': {e:?}");
+        }
+    });
+
+    let mut data = ctx.data.write().await;
+    data.entry::<CronJobs<Db>>().or_insert(Vec::new()).push(job);
+
+    Ok(())
+}
+
+/// The thread and its new name, if the name changes.
+async fn thread_name<Db: Database, Manager: ThreadTitleManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    post: &impl TemplateInfo,
+    thread: ChannelId,
+) -> Result<Option<(GuildChannel, String)>> {
+    let Some(channel) = thread.to_channel(ctx).await?.guild() else {
+        return Ok(None);
+    };
+
+    let base = match Manager::thread_base(pool, thread).await? {
+        Some(base) => base,
+        // Threads from before titles were kept were never prefixed, so their name is the base
+        None => {
+            Manager::save_thread_base(pool, thread, channel.name.clone()).await?;
+            channel.name.clone()
+        }
+    };
+
+    let name = match Manager::title_format(pool, channel.guild_id).await? {
+        Some(format) => format.apply(&base, post),
+        None => base,
+    };
+
+    Ok((name != channel.name).then_some((channel, name)))
 }
 
 pub enum Announcement {
-    Joined { user: UserId, alternative: bool },
+    Joined {
+        user: UserId,
+        alternative: bool,
+    },
     Left(UserId),
+    Kicked {
+        user: UserId,
+        actor: UserId,
+    },
+    /// An alternative moving into the fireteam.
+    Promoted(UserId),
+    Edited(UserId),
+    /// The post's new start time, as a Unix timestamp.
+    Rescheduled(i64),
 }
 
 impl Announcement {
-    pub async fn send(&self, ctx: &Context, channel: ChannelId) {
-        channel
-            .send_message(ctx, CreateMessage::new().content(format!("{self}")))
-            .await
-            .unwrap();
+    /// Announces the change the way the post, or else its guild, is set to.
+    pub async fn send<Db: Database, Manager: AnnouncementManager<Db>>(
+        &self,
+        ctx: &Context,
+        pool: &Pool<Db>,
+        guild: Option<GuildId>,
+        channel: ChannelId,
+        locale: &str,
+    ) -> Result<()> {
+        let mode = match Manager::post_announcements(pool, channel).await? {
+            Some(mode) => mode,
+            None => match guild {
+                Some(guild) => Manager::guild_announcements(pool, guild)
+                    .await?
+                    .unwrap_or_default(),
+                None => AnnouncementMode::default(),
+            },
+        };
+
+        match mode {
+            AnnouncementMode::Off => {}
+            AnnouncementMode::Each => {
+                channel
+                    .send_message(ctx, CreateMessage::new().content(self.message(locale)))
+                    .await?;
+            }
+            AnnouncementMode::Feed => {
+                let line = format!("<t:{}:t> {}", Utc::now().timestamp(), self.message(locale));
+                feed::<Db, Manager>(ctx, pool, channel, line).await?;
+            }
+        }
+
+        Ok(())
     }
-}
 
-impl Display for Announcement {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    pub fn message(&self, locale: &str) -> String {
         match self {
-            Announcement::Joined { user, alternative } if *alternative => {
-                write!(f, "{} joined as an alternative", user.mention())
-            }
+            Announcement::Joined { user, alternative } if *alternative => message_with(
+                locale,
+                "announcement.joined_alternative",
+                &[("user", &user.mention())],
+            ),
             Announcement::Joined { user, .. } => {
-                write!(f, "{} joined the fireteam", user.mention())
+                message_with(locale, "announcement.joined", &[("user", &user.mention())])
+            }
+            Announcement::Left(user) => {
+                message_with(locale, "announcement.left", &[("user", &user.mention())])
             }
-            Announcement::Left(user) => write!(f, "{} left the fireteam", user.mention()),
+            Announcement::Kicked { user, actor } => message_with(
+                locale,
+                "announcement.kicked",
+                &[("user", &user.mention()), ("actor", &actor.mention())],
+            ),
+            Announcement::Promoted(user) => message_with(
+                locale,
+                "announcement.promoted",
+                &[("user", &user.mention())],
+            ),
+            Announcement::Edited(user) => {
+                message_with(locale, "announcement.edited", &[("user", &user.mention())])
+            }
+            Announcement::Rescheduled(timestamp) => message_with(
+                locale,
+                "announcement.rescheduled",
+                &[("time", &format!("<t:{timestamp}:F>"))],
+            ),
         }
     }
 }
+
+static FEEDS: LazyLock<Mutex<HashMap<ChannelId, FeedLines>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Default)]
+struct FeedLines {
+    writing: bool,
+    /// Lines announced while the feed message was being written
+    pending: Vec<String>,
+}
+
+/// Adds the line to the post's activity feed message, starting a new one if it was deleted.
+///
+/// Only one edit of a channel's feed runs at a time. Lines announced meanwhile are added by the
+/// running call in one more edit, so none of them are lost to a concurrent edit.
+async fn feed<Db: Database, Manager: AnnouncementManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    channel: ChannelId,
+    line: String,
+) -> Result<()> {
+    {
+        let mut feeds = FEEDS.lock().unwrap();
+        let feed = feeds.entry(channel).or_default();
+
+        feed.pending.push(line);
+        if feed.writing {
+            return Ok(());
+        }
+        feed.writing = true;
+    }
+
+    loop {
+        let lines = {
+            let mut feeds = FEEDS.lock().unwrap();
+            let feed = feeds.entry(channel).or_default();
+
+            if feed.pending.is_empty() {
+                feeds.remove(&channel);
+                return Ok(());
+            }
+            std::mem::take(&mut feed.pending)
+        };
+
+        if let Err(e) = write_feed::<Db, Manager>(ctx, pool, channel, &lines).await {
+            FEEDS.lock().unwrap().remove(&channel);
+            return Err(e);
+        }
+    }
+}
+
+async fn write_feed<Db: Database, Manager: AnnouncementManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    channel: ChannelId,
+    new: &[String],
+) -> Result<()> {
+    if let Some(id) = Manager::feed_message(pool, channel).await? {
+        match channel.message(ctx, id).await.map_err(Error::from) {
+            Ok(mut message) => {
+                let mut lines = message.content.lines().collect::<Vec<_>>();
+                lines.extend(new.iter().map(String::as_str));
+                let skip = lines.len().saturating_sub(FEED_LINES);
+
+                message
+                    .edit(ctx, EditMessage::new().content(lines[skip..].join("\n")))
+                    .await?;
+
+                return Ok(());
+            }
+            Err(Error::MessageNotFound) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let skip = new.len().saturating_sub(FEED_LINES);
+    let message = channel
+        .send_message(ctx, CreateMessage::new().content(new[skip..].join("\n")))
+        .await?;
+    Manager::save_feed_message(pool, channel, message.id).await?;
+
+    Ok(())
+}
+
+/// Board refreshes of a guild closer together than this are coalesced into one.
+const SCHEDULE_DEBOUNCE_SECS: i64 = 2;
+
+static SCHEDULES: LazyLock<Mutex<HashMap<GuildId, ScheduleRefreshes>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Default)]
+struct ScheduleRefreshes {
+    pending: bool,
+    /// When the board's next post starts and has to be taken off
+    expires: Option<DateTime<Utc>>,
+}
+
+/// Refreshes the guild's schedule board shortly after, once for a burst of changes.
+///
+/// The refresh reads the posts as they are then, so callers can run it before saving.
+pub async fn sync_schedule<
+    Db: Database,
+    Manager: PostManager<Db> + ScheduleManager<Db> + LocaleManager<Db>,
+>(
+    ctx: &Context,
+    guild: Option<GuildId>,
+) {
+    let Some(guild) = guild else {
+        return;
+    };
+
+    {
+        let mut schedules = SCHEDULES.lock().unwrap();
+        let schedule = schedules.entry(guild).or_default();
+
+        if schedule.pending {
+            return;
+        }
+        schedule.pending = true;
+    }
+
+    let job = schedule_job::<Db, Manager>(
+        guild,
+        Utc::now() + Duration::seconds(SCHEDULE_DEBOUNCE_SECS),
+    );
+
+    let mut data = ctx.data.write().await;
+    data.entry::<CronJobs<Db>>().or_insert(Vec::new()).push(job);
+}
+
+/// A job refreshing the board at `at`, which schedules the next refresh for when the board's
+/// first post starts.
+fn schedule_job<
+    Db: Database,
+    Manager: PostManager<Db> + ScheduleManager<Db> + LocaleManager<Db>,
+>(
+    guild: GuildId,
+    at: DateTime<Utc>,
+) -> CronJob<Db> {
+    // Cron jobs run on whole seconds
+    let at = at + Duration::seconds(1);
+
+    CronJob::<Db>::new(&format!(
+        "{} {} {} {} {} * {}",
+        at.second(),
+        at.minute(),
+        at.hour(),
+        at.day(),
+        at.month(),
+        at.year()
+    ))
+    .set_action(move |ctx, pool| async move {
+        SCHEDULES.lock().unwrap().entry(guild).or_default().pending = false;
+
+        let expires = match refresh_schedule::<Db, Manager>(&ctx, &pool, guild).await {
+            Ok(expires) => expires,
+            Err(e) => {
+                eprintln!("Failed to refresh the schedule of '{guild}': {e}");
+                return;
+            }
+        };
+
+        let Some(expires) = expires else {
+            return;
+        };
+
+        {
+            let mut schedules = SCHEDULES.lock().unwrap();
+            let schedule = schedules.entry(guild).or_default();
+
+            if schedule.expires == Some(expires) {
+                return;
+            }
+            schedule.expires = Some(expires);
+        }
+
+        let job = schedule_job::<Db, Manager>(guild, expires);
+
+        let mut data = ctx.data.write().await;
+        data.entry::<CronJobs<Db>>().or_insert(Vec::new()).push(job);
+    })
+}
+
+/// Rewrites the board in the guild's scheduled thread, returning when its first post starts.
+async fn refresh_schedule<
+    Db: Database,
+    Manager: PostManager<Db> + ScheduleManager<Db> + LocaleManager<Db>,
+>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    guild: GuildId,
+) -> Result<Option<DateTime<Utc>>> {
+    let Some(thread) = Manager::scheduled_thread(pool, guild).await? else {
+        return Ok(None);
+    };
+
+    let now = Utc::now();
+    let posts =
+        Manager::guild_posts(pool, guild, now, Some(now + Duration::days(BOARD_DAYS))).await?;
+
+    let locale = guild_locale::<Db, Manager>(pool, Some(guild), None).await?;
+    let timezone = Manager::board_timezone(pool, guild)
+        .await?
+        .or_else(|| LOCALE_TO_TIMEZONE.get(locale.as_str()).copied())
+        .unwrap_or(Tz::UTC);
+
+    let mut old = Manager::board_messages(pool, guild).await?.into_iter();
+    let mut messages = Vec::new();
+    // Once a board message is missing, the rest are sent again to keep the board in order
+    let mut reposting = false;
+
+    for (embed, rows) in board(&posts, &locale, timezone) {
+        if !reposting && let Some(message) = old.next() {
+            let message_edit = EditMessage::new()
+                .embed(embed.clone())
+                .components(rows.clone());
+
+            match thread
+                .edit_message(ctx, message, message_edit)
+                .await
+                .map_err(Error::from)
+            {
+                Ok(_) => {
+                    messages.push(message);
+                    continue;
+                }
+                Err(Error::MessageNotFound) => reposting = true,
+                Err(e) => return Err(e),
+            }
+        }
+
+        let message = thread
+            .send_message(ctx, CreateMessage::new().embed(embed).components(rows))
+            .await?;
+        messages.push(message.id);
+    }
+
+    for message in old {
+        match thread
+            .delete_message(ctx, message)
+            .await
+            .map_err(Error::from)
+        {
+            Ok(_) | Err(Error::MessageNotFound) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Manager::save_board_messages(pool, guild, messages).await?;
+
+    Ok(posts.iter().map(|post| post.start_time).min())
+}